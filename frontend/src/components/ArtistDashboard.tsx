@@ -19,6 +19,8 @@ export const ArtistDashboard: React.FC<ArtistDashboardProps> = ({
 }) => {
 	const [isLoading, setIsLoading] = useState(false);
 	const [isRegistered, setIsRegistered] = useState(false);
+	// "Pending", "Verified" or "Suspended" once registered
+	const [artistStatus, setArtistStatus] = useState<string | null>(null);
 
 	useEffect(() => {
 		checkArtistStatus();
@@ -32,7 +34,9 @@ export const ArtistDashboard: React.FC<ArtistDashboardProps> = ({
 			const isArtist = await api.query.templatePallet.artists(
 				account.address
 			);
-			setIsRegistered(Boolean(isArtist.toJSON()));
+			const status = isArtist.toJSON() as string | null;
+			setIsRegistered(Boolean(status));
+			setArtistStatus(status);
 		} catch (error) {
 			toast.error("Failed to check artist status:" + error);
 		}
@@ -62,6 +66,7 @@ export const ArtistDashboard: React.FC<ArtistDashboardProps> = ({
 							`Registered as artist in block ${status.asInBlock.toString()}`
 						);
 						setIsRegistered(true);
+						setArtistStatus("Pending");
 					}
 				}
 			);
@@ -94,6 +99,21 @@ export const ArtistDashboard: React.FC<ArtistDashboardProps> = ({
 		);
 	}
 
+	if (artistStatus && artistStatus.toLowerCase() !== "verified") {
+		return (
+			<div className="max-w-2xl mx-auto p-4 sm:p-6 bg-white rounded-lg shadow-md">
+				<h2 className="text-xl sm:text-2xl font-bold text-gray-800 mb-4 sm:mb-6">
+					Artist Dashboard
+				</h2>
+				<p className="text-sm sm:text-base text-gray-600">
+					{artistStatus.toLowerCase() === "suspended"
+						? "Your artist account has been suspended."
+						: "Your registration is awaiting approval."}
+				</p>
+			</div>
+		);
+	}
+
 	return (
 		<div className="max-w-6xl mx-auto p-4 sm:p-6">
 			<h1 className="text-2xl sm:text-3xl font-bold mb-6 sm:mb-8 text-gray-900">
@@ -24,7 +24,7 @@ interface Collection {
 		price: string;
 		creator: string;
 	};
-	nfts: number[]; // List of NFT IDs in the collection
+	items: number; // Number of NFTs in the collection
 }
 
 interface NFTData {
@@ -37,7 +37,8 @@ interface CollectionData {
 	creator: string;
 	metadata: string;
 	is_frozen: boolean;
-	nfts: number[];
+	items: number;
+	maxSupply: number | null;
 }
 
 interface NFT {
@@ -116,7 +117,7 @@ export const CollectionManager: React.FC<CollectionManagerProps> = ({
 					return {
 						id,
 						metadata,
-						nfts: collection.nfts || [],
+						items: collection.items || 0,
 					};
 				})
 				.filter(
@@ -307,7 +308,7 @@ export const CollectionManager: React.FC<CollectionManagerProps> = ({
 								<div className="mt-2 flex justify-between items-center">
 									<span className="text-xs sm:text-sm text-gray-500">
 										Collection #{collection.id} (
-										{collection.nfts?.length || 0}{" "}
+										{collection.items || 0}{" "}
 										NFTs)
 									</span>
 								</div>
@@ -209,7 +209,7 @@ export const NFTMarketplace: React.FC<NFTMarketplaceProps> = ({
 				}
 			}
 
-			const tx = api.tx.templatePallet.mintNft(collectionId, itemId);
+			const tx = api.tx.templatePallet.mintNft(collectionId, itemId, null);
 			await tx.signAndSend(
 				account.address,
 				{ signer },
@@ -24,6 +24,7 @@ export const CreateCollectionModal: React.FC<CreateCollectionModalProps> = ({
 }) => {
 	const [title, setTitle] = useState("");
 	const [description, setDescription] = useState("");
+	const [royalty, setRoyalty] = useState("");
 	const [isLoading, setIsLoading] = useState(false);
 
 	const createCollection = async () => {
@@ -44,7 +45,15 @@ export const CreateCollectionModal: React.FC<CreateCollectionModalProps> = ({
 			const metadataBytes = Array.from(metadataString).map((c) =>
 				c.charCodeAt(0)
 			);
-			const tx = api.tx.templatePallet.createCollection(metadataBytes);
+			// Royalty is a Perbill: parts per billion of every sale price
+			const royaltyPercent = parseFloat(royalty) || 0;
+			const royaltyPerbill = Math.round(royaltyPercent * 10_000_000);
+			const tx = api.tx.templatePallet.createCollection(
+				metadataBytes,
+				royaltyPerbill,
+				null,
+				null
+			);
 
 			await tx.signAndSend(
 				account.address,
@@ -56,6 +65,7 @@ export const CreateCollectionModal: React.FC<CreateCollectionModalProps> = ({
 						);
 						setTitle("");
 						setDescription("");
+						setRoyalty("");
 						onSuccess();
 						onClose();
 					}
@@ -109,6 +119,22 @@ export const CreateCollectionModal: React.FC<CreateCollectionModalProps> = ({
 							rows={3}
 						/>
 					</div>
+
+					<div>
+						<label className="block text-sm font-medium text-gray-700 mb-1">
+							Royalty (%)
+						</label>
+						<input
+							type="number"
+							value={royalty}
+							onChange={(e) => setRoyalty(e.target.value)}
+							className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 placeholder:text-gray-400 text-gray-700"
+							placeholder="Share of every resale paid to you"
+							min={0}
+							max={25}
+							step={0.1}
+						/>
+					</div>
 					<div className="flex space-x-4 mt-6">
 						<Button
 							onClick={onClose}
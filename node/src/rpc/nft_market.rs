@@ -0,0 +1,289 @@
+//! `nftMarket_*` RPC methods.
+//!
+//! These wrap the runtime's `NftMarketplaceApi` so that clients such as the Farcaster frame
+//! backend can page through collections and NFTs without composing raw storage queries.
+
+use std::{fmt::Display, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject, ErrorObjectOwned},
+};
+use pallet_parachain_template::{Collection, CollectionId, ItemId, NFT};
+use parachain_template_runtime::apis::NftMarketplaceApi as NftMarketplaceRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{traits::Block as BlockT, Perbill};
+
+/// Page size used when the caller does not pass a `limit`.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// `Balance` only fixes the runtime API balance type; prices are returned as `NumberOrHex`.
+#[rpc(server)]
+pub trait NftMarketApi<BlockHash, AccountId, Balance> {
+	/// List collections, starting after `cursor`.
+	#[method(name = "nftMarket_collections")]
+	fn collections(
+		&self,
+		cursor: Option<CollectionId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CollectionInfo<AccountId>>>;
+
+	/// List the items of a collection, starting after `cursor`.
+	#[method(name = "nftMarket_collectionItems")]
+	fn collection_items(
+		&self,
+		collection_id: CollectionId,
+		cursor: Option<ItemId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<NftInfo<AccountId>>>;
+
+	/// List the NFTs owned by `account`, starting after `cursor`.
+	#[method(name = "nftMarket_nftsOfOwner")]
+	fn nfts_of_owner(
+		&self,
+		account: AccountId,
+		cursor: Option<(CollectionId, ItemId)>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<NftInfo<AccountId>>>;
+
+	/// Fetch a single NFT with its decoded metadata, price and sale status.
+	#[method(name = "nftMarket_nft")]
+	fn nft(
+		&self,
+		collection_id: CollectionId,
+		item_id: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NftInfo<AccountId>>>;
+}
+
+/// A collection as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionInfo<AccountId> {
+	/// Id of the collection.
+	pub collection_id: CollectionId,
+	/// Artist who created the collection.
+	pub creator: AccountId,
+	/// Metadata parsed as JSON, or as a plain string when it is not JSON.
+	pub metadata: serde_json::Value,
+	/// Metadata bytes as stored on chain.
+	pub raw_metadata: Bytes,
+	/// Number of items in the collection.
+	pub item_count: u32,
+	/// Most items the collection may hold, if capped.
+	pub max_supply: Option<u32>,
+	/// Share of every sale paid to `royalty_recipient`, in parts per billion.
+	pub royalty: Perbill,
+	/// Account receiving royalties.
+	pub royalty_recipient: AccountId,
+	/// Frozen collections accept no new items, metadata edits or burns.
+	pub is_frozen: bool,
+}
+
+/// An NFT as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftInfo<AccountId> {
+	/// Collection the item belongs to.
+	pub collection_id: CollectionId,
+	/// Id of the item within its collection.
+	pub item_id: ItemId,
+	/// Current owner.
+	pub owner: AccountId,
+	/// Metadata parsed as JSON, or as a plain string when it is not JSON.
+	pub metadata: serde_json::Value,
+	/// Metadata bytes as stored on chain.
+	pub raw_metadata: Bytes,
+	/// Last listing or sale price.
+	pub price: NumberOrHex,
+	/// Whether the item has changed hands since it was created.
+	pub is_sold: bool,
+	/// Current asking price, `None` when the item is not for sale.
+	pub listing_price: Option<NumberOrHex>,
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query the NFT marketplace.
+pub struct NftMarket<C, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> NftMarket<C, Block> {
+	/// Creates a new instance of the NftMarket Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+fn decode_metadata(bytes: &[u8]) -> serde_json::Value {
+	serde_json::from_slice(bytes)
+		.unwrap_or_else(|_| serde_json::Value::String(String::from_utf8_lossy(bytes).into_owned()))
+}
+
+fn try_into_rpc_balance<Balance>(value: Balance) -> RpcResult<NumberOrHex>
+where
+	Balance: Display + Copy + TryInto<NumberOrHex>,
+{
+	value.try_into().map_err(|_| {
+		ErrorObject::owned(
+			ErrorCode::InternalError.code(),
+			format!("{} doesn't fit in NumberOrHex representation", value),
+			None::<()>,
+		)
+	})
+}
+
+fn nft_info<AccountId, Balance>(
+	collection_id: CollectionId,
+	item_id: ItemId,
+	nft: NFT<AccountId, Balance>,
+	listing_price: Option<Balance>,
+) -> RpcResult<NftInfo<AccountId>>
+where
+	Balance: Display + Copy + TryInto<NumberOrHex>,
+{
+	Ok(NftInfo {
+		collection_id,
+		item_id,
+		owner: nft.owner,
+		metadata: decode_metadata(&nft.metadata),
+		raw_metadata: nft.metadata.into_inner().into(),
+		price: try_into_rpc_balance(nft.price)?,
+		is_sold: nft.is_sold,
+		listing_price: listing_price.map(try_into_rpc_balance).transpose()?,
+	})
+}
+
+fn collection_info<AccountId>(
+	collection_id: CollectionId,
+	collection: Collection<AccountId>,
+) -> CollectionInfo<AccountId> {
+	CollectionInfo {
+		collection_id,
+		creator: collection.creator,
+		metadata: decode_metadata(&collection.metadata),
+		raw_metadata: collection.metadata.into_inner().into(),
+		item_count: collection.items,
+		max_supply: collection.max_supply,
+		royalty: collection.royalty,
+		royalty_recipient: collection.royalty_recipient,
+		is_frozen: collection.is_frozen,
+	}
+}
+
+impl<C, Block, AccountId, Balance> NftMarketApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for NftMarket<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: NftMarketplaceRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+	Balance: Codec + Display + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn collections(
+		&self,
+		cursor: Option<CollectionId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<CollectionInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let collections = api
+			.collections(at_hash, cursor, limit.unwrap_or(DEFAULT_PAGE_SIZE))
+			.map_err(|e| runtime_error(e, "Unable to query collections."))?;
+
+		Ok(collections
+			.into_iter()
+			.map(|(collection_id, collection)| collection_info(collection_id, collection))
+			.collect())
+	}
+
+	fn collection_items(
+		&self,
+		collection_id: CollectionId,
+		cursor: Option<ItemId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<NftInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let items = api
+			.collection_items(at_hash, collection_id, cursor, limit.unwrap_or(DEFAULT_PAGE_SIZE))
+			.map_err(|e| runtime_error(e, "Unable to query collection items."))?;
+
+		items
+			.into_iter()
+			.map(|(item_id, (nft, listing_price))| {
+				nft_info(collection_id, item_id, nft, listing_price)
+			})
+			.collect()
+	}
+
+	fn nfts_of_owner(
+		&self,
+		account: AccountId,
+		cursor: Option<(CollectionId, ItemId)>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<NftInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let nfts = api
+			.nfts_of_owner(at_hash, account, cursor, limit.unwrap_or(DEFAULT_PAGE_SIZE))
+			.map_err(|e| runtime_error(e, "Unable to query NFTs of owner."))?;
+
+		nfts.into_iter()
+			.map(|(collection_id, item_id, (nft, listing_price))| {
+				nft_info(collection_id, item_id, nft, listing_price)
+			})
+			.collect()
+	}
+
+	fn nft(
+		&self,
+		collection_id: CollectionId,
+		item_id: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<NftInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let nft = api
+			.nft_details(at_hash, collection_id, item_id)
+			.map_err(|e| runtime_error(e, "Unable to query NFT details."))?;
+
+		nft.map(|(nft, listing_price)| nft_info(collection_id, item_id, nft, listing_price))
+			.transpose()
+	}
+}
@@ -0,0 +1,71 @@
+//! Benchmarking setup for pallet-farcaster-registry
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use alloc::{vec, vec::Vec};
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Get, SortedMembers};
+use frame_system::RawOrigin;
+use sp_core::{ed25519, H160};
+
+/// Fill the committee up to [`Config::MaxMembers`] and return its members.
+fn full_committee<T: Config>() -> Vec<T::AccountId> {
+	let missing = T::MaxMembers::get().saturating_sub(T::Committee::count() as u32);
+	for index in 0..missing {
+		T::Committee::add(&account("member", index, 0));
+	}
+	T::Committee::sorted_members()
+}
+
+/// `e` transfers, each of an FID to an address that already owns another FID, so that every
+/// event rewrites the custody of both.
+fn transfers<T: Config>(e: u32) -> BatchOf<T> {
+	let custody = |index: u32| H160::from_low_u64_be(index.into());
+	for index in 0..2 * e {
+		FidCustody::<T>::insert(Fid::from(index), custody(index));
+		CustodyFid::<T>::insert(custody(index), Fid::from(index));
+	}
+	let events: Vec<_> = (0..e)
+		.map(|index| RegistryEvent::Transfer { fid: index.into(), custody: custody(e + index) })
+		.collect();
+	events.try_into().unwrap()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+	#[cfg(test)]
+	use crate::pallet::Pallet as Registry;
+
+	/// The vote that reaches the threshold, cast by a member moving its vote from another batch.
+	#[benchmark]
+	fn submit_batch(e: Linear<0, { T::MaxEventsPerBatch::get() }>) {
+		let members = full_committee::<T>();
+		let threshold = T::Threshold::get().mul_ceil(members.len() as u32).max(1) as usize;
+		let sequence = NextSequence::<T>::get();
+		let events = transfers::<T>(e);
+		let (caller, others) = members.split_last().unwrap();
+		for member in &others[..threshold - 1] {
+			Pallet::<T>::submit_batch(
+				RawOrigin::Signed(member.clone()).into(),
+				sequence,
+				events.clone(),
+			)
+			.unwrap();
+		}
+		if threshold > 1 {
+			let key = ed25519::Public::from_raw([0; 32]);
+			let other = vec![RegistryEvent::AddKey { fid: 0, key }].try_into().unwrap();
+			Pallet::<T>::submit_batch(RawOrigin::Signed(caller.clone()).into(), sequence, other)
+				.unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), sequence, events);
+
+		assert_eq!(NextSequence::<T>::get(), sequence + 1);
+		assert_eq!(Votes::<T>::iter_prefix(sequence).count(), 0);
+	}
+
+	impl_benchmark_test_suite!(Registry, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,336 @@
+//! Mirror of the Farcaster IdRegistry and KeyRegistry.
+//!
+//! Members of an oracle committee watch the registries on Optimism and submit their events in
+//! numbered batches. A batch is applied once enough members submitted the same events for the
+//! next sequence number; diverging submissions for that number are tallied separately and dropped
+//! once one of them wins. Members may change their vote until then, and changes to the committee
+//! drop the votes of departing members. Votes are counted against the current committee, so a
+//! member submitting its batch again applies it once the remaining votes suffice and a split vote
+//! never leaves a sequence number stuck.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+// The `TryState` impl that `frame_support` generates logs failures with `map_err`, which trips
+// `manual_inspect` under `try-runtime`.
+#[cfg_attr(feature = "try-runtime", allow(clippy::manual_inspect))]
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::vec::Vec;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{ChangeMembers, Contains, SortedMembers},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_core::{ed25519, H160, H256};
+    use sp_runtime::Perbill;
+
+    /// A Farcaster account id.
+    pub type Fid = u64;
+
+    /// An IdRegistry or KeyRegistry event, as observed on Optimism.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum RegistryEvent {
+        /// IdRegistry `Register`: a new FID owned by `custody`.
+        Register { fid: Fid, custody: H160 },
+        /// IdRegistry `Transfer` or `Recover`: the FID moved to `custody`.
+        Transfer { fid: Fid, custody: H160 },
+        /// KeyRegistry `Add` of an ed25519 signer key.
+        AddKey { fid: Fid, key: ed25519::Public },
+        /// KeyRegistry `Remove`.
+        RemoveKey { fid: Fid, key: ed25519::Public },
+        /// KeyRegistry `AdminReset`: the key was removed by the registry admin.
+        AdminReset { fid: Fid, key: ed25519::Public },
+    }
+
+    pub type BatchOf<T> = BoundedVec<RegistryEvent, <T as Config>::MaxEventsPerBatch>;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type WeightInfo: WeightInfo;
+        /// Members allowed to submit batches.
+        type Committee: SortedMembers<Self::AccountId>;
+        /// Share of the committee that must submit the same batch before it is applied.
+        #[pallet::constant]
+        type Threshold: Get<Perbill>;
+        /// Largest committee whose votes can be tallied.
+        #[pallet::constant]
+        type MaxMembers: Get<u32>;
+        /// Most events in a single batch.
+        #[pallet::constant]
+        type MaxEventsPerBatch: Get<u32>;
+        /// Origin allowed to apply a batch without a committee vote, e.g. to settle a dispute.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    /// Signer keys registered for each FID.
+    #[pallet::storage]
+    #[pallet::getter(fn fid_signers)]
+    pub type FidSigners<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, Fid, Blake2_128Concat, ed25519::Public, (), OptionQuery>;
+
+    /// Custody address owning each FID.
+    #[pallet::storage]
+    #[pallet::getter(fn fid_custody)]
+    pub type FidCustody<T: Config> = StorageMap<_, Twox64Concat, Fid, H160, OptionQuery>;
+
+    /// FID owned by each custody address.
+    #[pallet::storage]
+    #[pallet::getter(fn custody_fid)]
+    pub type CustodyFid<T: Config> = StorageMap<_, Blake2_128Concat, H160, Fid, OptionQuery>;
+
+    /// Sequence number of the next batch to apply.
+    #[pallet::storage]
+    #[pallet::getter(fn next_sequence)]
+    pub type NextSequence<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Members that submitted each batch of the pending sequence numbers, by batch hash.
+    #[pallet::storage]
+    #[pallet::getter(fn votes)]
+    pub type Votes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Identity,
+        H256,
+        BoundedVec<T::AccountId, T::MaxMembers>,
+        ValueQuery,
+    >;
+
+    /// Hash of the batch each member submitted for a sequence number.
+    #[pallet::storage]
+    pub type Voted<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u64, Blake2_128Concat, T::AccountId, H256, OptionQuery>;
+
+    /// Events of each batch with votes for a pending sequence number, by batch hash.
+    #[pallet::storage]
+    pub type Batches<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u64, Identity, H256, BatchOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// (sequence, batch hash, member)
+        BatchSubmitted(u64, H256, T::AccountId),
+        /// A member submitted a batch that differs from an earlier submission: (sequence, hash).
+        ConflictingBatch(u64, H256),
+        /// (sequence, batch hash)
+        BatchApplied(u64, H256),
+        SignerAdded(Fid, ed25519::Public),
+        SignerRemoved(Fid, ed25519::Public),
+        /// (fid, new custody address)
+        CustodyChanged(Fid, H160),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotMember,
+        /// Batches must be submitted for the next sequence number.
+        WrongSequence,
+        /// The member already submitted this batch for this sequence number and it is not approved.
+        AlreadySubmitted,
+        TooManyVotes,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Submit the registry events for sequence number `sequence` (committee members only)
+        ///
+        /// The batch is applied once the threshold of current members submitted the same events.
+        /// Submitting a different batch moves the member's vote to it, and submitting the same
+        /// batch again counts its votes again, e.g. after the committee shrank.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::submit_batch(events.len() as u32))]
+        pub fn submit_batch(
+            origin: OriginFor<T>,
+            sequence: u64,
+            events: BatchOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let members = T::Committee::sorted_members();
+            ensure!(members.binary_search(&who).is_ok(), Error::<T>::NotMember);
+            ensure!(
+                sequence == NextSequence::<T>::get(),
+                Error::<T>::WrongSequence
+            );
+
+            let hash = Self::batch_hash(sequence, &events);
+            match Voted::<T>::get(sequence, &who) {
+                Some(previous) if previous == hash => {
+                    let votes = Votes::<T>::get(sequence, hash);
+                    ensure!(
+                        Self::is_approved(&votes, &members),
+                        Error::<T>::AlreadySubmitted
+                    );
+                    Self::apply_batch(sequence, hash, events);
+                    return Ok(());
+                }
+                Some(previous) => Self::withdraw_vote(sequence, previous, &who),
+                None => {}
+            }
+            if Votes::<T>::iter_key_prefix(sequence).any(|other| other != hash) {
+                Self::deposit_event(Event::ConflictingBatch(sequence, hash));
+            }
+            let votes = Votes::<T>::try_mutate(sequence, hash, |votes| {
+                votes
+                    .try_push(who.clone())
+                    .map_err(|_| Error::<T>::TooManyVotes)?;
+                Ok::<_, Error<T>>(votes.clone())
+            })?;
+            Voted::<T>::insert(sequence, &who, hash);
+            Self::deposit_event(Event::BatchSubmitted(sequence, hash, who));
+
+            if Self::is_approved(&votes, &members) {
+                Self::apply_batch(sequence, hash, events);
+            } else if !Batches::<T>::contains_key(sequence, hash) {
+                Batches::<T>::insert(sequence, hash, events);
+            }
+            Ok(())
+        }
+
+        /// Apply the batch for the next sequence number without a committee vote
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::submit_batch(events.len() as u32))]
+        pub fn force_apply_batch(
+            origin: OriginFor<T>,
+            sequence: u64,
+            events: BatchOf<T>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(
+                sequence == NextSequence::<T>::get(),
+                Error::<T>::WrongSequence
+            );
+
+            let hash = Self::batch_hash(sequence, &events);
+            Self::apply_batch(sequence, hash, events);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn batch_hash(sequence: u64, events: &BatchOf<T>) -> H256 {
+            sp_io::hashing::blake2_256(&(sequence, events).encode()).into()
+        }
+
+        /// Whether the threshold of `members` voted for a batch. Members removed since they voted
+        /// no longer count.
+        fn is_approved(votes: &[T::AccountId], members: &[T::AccountId]) -> bool {
+            let approvals = votes
+                .iter()
+                .filter(|voter| members.binary_search(voter).is_ok())
+                .count() as u32;
+            approvals >= T::Threshold::get().mul_ceil(members.len() as u32).max(1)
+        }
+
+        /// Remove the vote of `who` for the batch `hash`, dropping the batch once it has no votes.
+        fn withdraw_vote(sequence: u64, hash: H256, who: &T::AccountId) {
+            Voted::<T>::remove(sequence, who);
+            let mut votes = Votes::<T>::get(sequence, hash);
+            votes.retain(|voter| voter != who);
+            if votes.is_empty() {
+                Votes::<T>::remove(sequence, hash);
+                Batches::<T>::remove(sequence, hash);
+            } else {
+                Votes::<T>::insert(sequence, hash, votes);
+            }
+        }
+
+        /// Apply `events`, move on to the next sequence number and drop the votes on this one.
+        fn apply_batch(sequence: u64, hash: H256, events: BatchOf<T>) {
+            for event in events {
+                Self::apply_event(event);
+            }
+            NextSequence::<T>::put(sequence.saturating_add(1));
+            let limit = T::MaxMembers::get();
+            let _ = Votes::<T>::clear_prefix(sequence, limit, None);
+            let _ = Voted::<T>::clear_prefix(sequence, limit, None);
+            let _ = Batches::<T>::clear_prefix(sequence, limit, None);
+            Self::deposit_event(Event::BatchApplied(sequence, hash));
+        }
+
+        fn apply_event(event: RegistryEvent) {
+            match event {
+                RegistryEvent::Register { fid, custody }
+                | RegistryEvent::Transfer { fid, custody } => {
+                    if let Some(previous) = FidCustody::<T>::get(fid) {
+                        CustodyFid::<T>::remove(previous);
+                    }
+                    // An address owns at most one FID
+                    if let Some(previous_fid) = CustodyFid::<T>::get(custody) {
+                        FidCustody::<T>::remove(previous_fid);
+                    }
+                    FidCustody::<T>::insert(fid, custody);
+                    CustodyFid::<T>::insert(custody, fid);
+                    Self::deposit_event(Event::CustodyChanged(fid, custody));
+                }
+                RegistryEvent::AddKey { fid, key } => {
+                    FidSigners::<T>::insert(fid, key, ());
+                    Self::deposit_event(Event::SignerAdded(fid, key));
+                }
+                RegistryEvent::RemoveKey { fid, key } | RegistryEvent::AdminReset { fid, key } => {
+                    if FidSigners::<T>::take(fid, key).is_some() {
+                        Self::deposit_event(Event::SignerRemoved(fid, key));
+                    }
+                }
+            }
+        }
+
+        /// Whether `key` is a registered signer of `fid`.
+        pub fn is_signer(fid: Fid, key: &ed25519::Public) -> bool {
+            FidSigners::<T>::contains_key(fid, key)
+        }
+
+        /// Signer keys registered for `fid`.
+        pub fn signers_of(fid: Fid) -> Vec<ed25519::Public> {
+            FidSigners::<T>::iter_key_prefix(fid).collect()
+        }
+    }
+
+    /// Drops the votes of departing members on the next sequence number. Nothing is applied here,
+    /// where the weight could not be charged; a batch the remaining members approve is applied by
+    /// the next submission for it or by `force_apply_batch`.
+    impl<T: Config> ChangeMembers<T::AccountId> for Pallet<T> {
+        fn change_members_sorted(
+            _incoming: &[T::AccountId],
+            outgoing: &[T::AccountId],
+            _sorted_new: &[T::AccountId],
+        ) {
+            let sequence = NextSequence::<T>::get();
+            for who in outgoing {
+                if let Some(hash) = Voted::<T>::get(sequence, who) {
+                    Self::withdraw_vote(sequence, hash, who);
+                }
+            }
+        }
+    }
+
+    /// Set of `(fid, key)` pairs where `key` is a registered signer of `fid`.
+    pub struct RegisteredSigners<T>(PhantomData<T>);
+
+    impl<T: Config> Contains<(Fid, ed25519::Public)> for RegisteredSigners<T> {
+        fn contains((fid, key): &(Fid, ed25519::Public)) -> bool {
+            Pallet::<T>::is_signer(*fid, key)
+        }
+    }
+}
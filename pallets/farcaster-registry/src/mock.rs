@@ -0,0 +1,86 @@
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ChangeMembers, SortedMembers},
+};
+use frame_system::{mocking::MockBlock, EnsureRoot};
+use sp_runtime::{traits::ConstU32, BuildStorage, Perbill};
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const DAVE: AccountId = 4;
+
+// Configure a mock runtime to test the pallet.
+#[frame_support::runtime]
+mod test_runtime {
+	#[runtime::runtime]
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask
+	)]
+	pub struct Test;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system;
+	#[runtime::pallet_index(1)]
+	pub type Registry = crate;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = MockBlock<Test>;
+}
+
+parameter_types! {
+	pub static Members: Vec<AccountId> = vec![ALICE, BOB, CHARLIE];
+	pub const Threshold: Perbill = Perbill::from_percent(66);
+}
+
+pub struct Committee;
+impl SortedMembers<AccountId> for Committee {
+	fn sorted_members() -> Vec<AccountId> {
+		Members::get()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &AccountId) {
+		let mut members = Members::get();
+		members.push(*who);
+		members.sort();
+		set_members(members);
+	}
+}
+
+/// Replace the committee, notifying the pallet like a membership pallet would.
+pub fn set_members(new: Vec<AccountId>) {
+	let old = Members::get();
+	Members::set(new.clone());
+	<Registry as ChangeMembers<AccountId>>::set_members_sorted(&new, &old);
+}
+
+impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Committee = Committee;
+	type Threshold = Threshold;
+	type MaxMembers = ConstU32<4>;
+	type MaxEventsPerBatch = ConstU32<4>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
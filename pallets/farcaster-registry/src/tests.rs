@@ -0,0 +1,170 @@
+use crate::{mock::*, Batches, Error, Event, RegistryEvent, Voted, Votes};
+use frame_support::{
+	assert_noop, assert_ok, dispatch::DispatchResult, traits::ConstU32, BoundedVec,
+};
+use sp_core::{ed25519, H160};
+
+fn key(seed: u8) -> ed25519::Public {
+	ed25519::Public::from_raw([seed; 32])
+}
+
+fn address(seed: u8) -> H160 {
+	H160::repeat_byte(seed)
+}
+
+fn batch(events: Vec<RegistryEvent>) -> BoundedVec<RegistryEvent, ConstU32<4>> {
+	events.try_into().unwrap()
+}
+
+fn submit(who: AccountId, sequence: u64, events: Vec<RegistryEvent>) -> DispatchResult {
+	Registry::submit_batch(RuntimeOrigin::signed(who), sequence, batch(events))
+}
+
+#[test]
+fn batch_applies_at_threshold() {
+	new_test_ext().execute_with(|| {
+		let events = vec![
+			RegistryEvent::Register { fid: 42, custody: address(1) },
+			RegistryEvent::AddKey { fid: 42, key: key(1) },
+		];
+		assert_noop!(submit(DAVE, 0, events.clone()), Error::<Test>::NotMember);
+		assert_noop!(submit(ALICE, 1, events.clone()), Error::<Test>::WrongSequence);
+
+		assert_ok!(submit(ALICE, 0, events.clone()));
+		assert_noop!(submit(ALICE, 0, events.clone()), Error::<Test>::AlreadySubmitted);
+		// One of three members is below the threshold
+		assert_eq!(Registry::next_sequence(), 0);
+		assert!(!Registry::is_signer(42, &key(1)));
+
+		assert_ok!(submit(BOB, 0, events.clone()));
+		assert_eq!(Registry::next_sequence(), 1);
+		assert!(Registry::is_signer(42, &key(1)));
+		assert_eq!(Registry::signers_of(42), vec![key(1)]);
+		assert_eq!(Registry::fid_custody(42), Some(address(1)));
+		assert_eq!(Registry::custody_fid(address(1)), Some(42));
+		assert_eq!(Votes::<Test>::iter_prefix(0).count(), 0);
+
+		// The late vote is for a sequence number already applied
+		assert_noop!(submit(CHARLIE, 0, events), Error::<Test>::WrongSequence);
+	});
+}
+
+#[test]
+fn conflicting_batches_are_tallied_separately() {
+	new_test_ext().execute_with(|| {
+		let honest = vec![RegistryEvent::AddKey { fid: 42, key: key(1) }];
+		let forged = vec![RegistryEvent::AddKey { fid: 42, key: key(66) }];
+
+		assert_ok!(submit(ALICE, 0, forged.clone()));
+		assert_ok!(submit(BOB, 0, honest.clone()));
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Registry(Event::ConflictingBatch(0, _))
+		)));
+		assert_eq!(Registry::next_sequence(), 0);
+
+		assert_ok!(submit(CHARLIE, 0, honest));
+		assert_eq!(Registry::next_sequence(), 1);
+		assert!(Registry::is_signer(42, &key(1)));
+		assert!(!Registry::is_signer(42, &key(66)));
+	});
+}
+
+#[test]
+fn removed_members_no_longer_count() {
+	new_test_ext().execute_with(|| {
+		let events = vec![RegistryEvent::AddKey { fid: 42, key: key(1) }];
+		assert_ok!(submit(ALICE, 0, events.clone()));
+		set_members(vec![BOB, CHARLIE]);
+		assert_eq!(Voted::<Test>::get(0, ALICE), None);
+		assert_ok!(submit(BOB, 0, events.clone()));
+		assert_eq!(Registry::next_sequence(), 0);
+		assert_ok!(submit(CHARLIE, 0, events));
+		assert_eq!(Registry::next_sequence(), 1);
+	});
+}
+
+#[test]
+fn members_can_change_their_vote() {
+	new_test_ext().execute_with(|| {
+		let honest = vec![RegistryEvent::AddKey { fid: 42, key: key(1) }];
+		let forged = vec![RegistryEvent::AddKey { fid: 42, key: key(66) }];
+		let other = vec![RegistryEvent::AddKey { fid: 42, key: key(7) }];
+
+		// A three-way split reaches no threshold
+		assert_ok!(submit(ALICE, 0, forged.clone()));
+		assert_ok!(submit(BOB, 0, honest.clone()));
+		assert_ok!(submit(CHARLIE, 0, other));
+		assert_eq!(Registry::next_sequence(), 0);
+		assert_eq!(Batches::<Test>::iter_prefix(0).count(), 3);
+
+		assert_ok!(submit(ALICE, 0, honest.clone()));
+		assert_eq!(Registry::next_sequence(), 1);
+		assert!(Registry::is_signer(42, &key(1)));
+		assert!(!Registry::is_signer(42, &key(66)));
+		assert_eq!(Votes::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Voted::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Batches::<Test>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn committee_changes_drop_departing_votes() {
+	new_test_ext().execute_with(|| {
+		let honest = vec![RegistryEvent::AddKey { fid: 42, key: key(1) }];
+		let forged = vec![RegistryEvent::AddKey { fid: 42, key: key(66) }];
+		assert_ok!(submit(ALICE, 0, honest.clone()));
+		assert_ok!(submit(BOB, 0, forged));
+		assert_noop!(submit(ALICE, 0, honest.clone()), Error::<Test>::AlreadySubmitted);
+
+		// The departing member's batch goes with their vote
+		set_members(vec![ALICE, CHARLIE]);
+		assert_eq!(Votes::<Test>::iter_prefix(0).count(), 1);
+		assert_eq!(Batches::<Test>::iter_prefix(0).count(), 1);
+		assert_eq!(Registry::next_sequence(), 0);
+
+		// The remaining vote meets the threshold of the smaller committee once submitted again
+		set_members(vec![ALICE]);
+		assert_eq!(Registry::next_sequence(), 0);
+		assert_ok!(submit(ALICE, 0, honest));
+		assert_eq!(Registry::next_sequence(), 1);
+		assert!(Registry::is_signer(42, &key(1)));
+		assert!(!Registry::is_signer(42, &key(66)));
+	});
+}
+
+#[test]
+fn key_removals_and_transfers_update_the_mirror() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Registry::force_apply_batch(
+			RuntimeOrigin::root(),
+			0,
+			batch(vec![
+				RegistryEvent::Register { fid: 42, custody: address(1) },
+				RegistryEvent::AddKey { fid: 42, key: key(1) },
+				RegistryEvent::AddKey { fid: 42, key: key(2) },
+				RegistryEvent::AddKey { fid: 42, key: key(3) },
+			])
+		));
+
+		assert_noop!(
+			Registry::force_apply_batch(RuntimeOrigin::signed(ALICE), 1, batch(vec![])),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Registry::force_apply_batch(
+			RuntimeOrigin::root(),
+			1,
+			batch(vec![
+				RegistryEvent::RemoveKey { fid: 42, key: key(1) },
+				RegistryEvent::AdminReset { fid: 42, key: key(2) },
+				RegistryEvent::Transfer { fid: 42, custody: address(2) },
+			])
+		));
+		System::assert_has_event(Event::CustodyChanged(42, address(2)).into());
+		assert_eq!(Registry::signers_of(42), vec![key(3)]);
+		assert_eq!(Registry::fid_custody(42), Some(address(2)));
+		assert_eq!(Registry::custody_fid(address(1)), None);
+		assert_eq!(Registry::custody_fid(address(2)), Some(42));
+		assert_eq!(Registry::next_sequence(), 2);
+	});
+}
@@ -0,0 +1,68 @@
+//! Weights for pallet_farcaster_registry
+//!
+//! Estimated from the storage accesses of each call, not measured. Replace this file with the
+//! output of the command below, run on reference hardware.
+
+// Command:
+// ./target/release/parachain-template-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_farcaster_registry
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/farcaster-registry/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_farcaster_registry.
+pub trait WeightInfo {
+	fn submit_batch(e: u32, ) -> Weight;
+}
+
+/// Weights for pallet_farcaster_registry using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: OracleCommittee Members (r:1 w:0)
+	/// Storage: FarcasterRegistry NextSequence (r:1 w:1)
+	/// Storage: FarcasterRegistry Voted (r:1 w:17)
+	/// Storage: FarcasterRegistry Votes (r:16 w:17)
+	/// Storage: FarcasterRegistry Batches (r:1 w:16)
+	/// Storage: FarcasterRegistry FidCustody (r:100 w:100)
+	/// Storage: FarcasterRegistry CustodyFid (r:100 w:200)
+	/// Storage: FarcasterRegistry FidSigners (r:0 w:100)
+	/// The range of component `e` is `[0, 100]`.
+	fn submit_batch(e: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 8_000)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(51_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(e.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn submit_batch(e: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 8_000)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes(51_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(e.into()))
+	}
+}
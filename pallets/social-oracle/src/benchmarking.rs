@@ -0,0 +1,207 @@
+//! Benchmarking setup for pallet-social-oracle
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	pallet_prelude::BoundedVec,
+	traits::{fungible::Mutate, EnsureOrigin, Get, SortedMembers},
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Saturating};
+
+const LIKED: Subject = Subject::Liked { fid: 42, cast_hash: [7; 20] };
+
+/// Give `who` enough funds to cover any bond or deposit.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::set_balance(who, BalanceOf::<T>::max_value() / 1_000u32.into());
+}
+
+/// Admit `who` as a candidate and have them bond.
+fn bonded_member<T: Config>(who: &T::AccountId) {
+	fund::<T>(who);
+	if !T::Candidates::contains(who) {
+		T::Candidates::add(who);
+	}
+	Pallet::<T>::bond(RawOrigin::Signed(who.clone()).into()).unwrap();
+}
+
+/// Bond `n` new members and return them, sorted.
+fn bonded_members<T: Config>(n: u32) -> Vec<T::AccountId> {
+	let mut members: Vec<T::AccountId> = (0..n).map(|index| account("member", index, 0)).collect();
+	members.iter().for_each(bonded_member::<T>);
+	members.sort();
+	members
+}
+
+/// Finalise `value` for [`LIKED`], attested by `attesters`.
+fn finalise<T: Config>(value: u64, attesters: Vec<T::AccountId>) {
+	Attestations::<T>::insert(
+		LIKED,
+		Attestation {
+			value,
+			finalised_at: frame_system::Pallet::<T>::block_number(),
+			attesters: BoundedVec::truncate_from(attesters),
+		},
+	);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+	#[cfg(test)]
+	use crate::pallet::Pallet as SocialOracle;
+
+	#[benchmark]
+	fn bond() {
+		bonded_members::<T>(T::MaxMembers::get() - 1);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		T::Candidates::add(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(Members::<T>::get().contains(&caller));
+	}
+
+	#[benchmark]
+	fn unbond() {
+		let caller = bonded_members::<T>(T::MaxMembers::get()).remove(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(Unbonding::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn withdraw_bond() {
+		let caller = bonded_members::<T>(1).remove(0);
+		Pallet::<T>::unbond(RawOrigin::Signed(caller.clone()).into()).unwrap();
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::UnbondingPeriod::get()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Bonds::<T>::contains_key(&caller));
+	}
+
+	/// The attestation that reaches the threshold, after every other member attested.
+	#[benchmark]
+	fn attest() {
+		let members = bonded_members::<T>(T::MaxMembers::get());
+		let threshold = T::Threshold::get().mul_ceil(members.len() as u32).max(1) as usize;
+		let (caller, others) = members.split_last().unwrap();
+		let (agreeing, disagreeing) = others.split_at(threshold - 1);
+		for member in agreeing {
+			Pallet::<T>::attest(RawOrigin::Signed(member.clone()).into(), LIKED, 1).unwrap();
+		}
+		// Each attests a different value, which cannot finalise unless a single member suffices
+		if threshold > 1 {
+			for (value, member) in (2..).zip(disagreeing) {
+				Pallet::<T>::attest(RawOrigin::Signed(member.clone()).into(), LIKED, value)
+					.unwrap();
+			}
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), LIKED, 1);
+
+		assert_eq!(Pallet::<T>::attested(&LIKED), Some(1));
+	}
+
+	#[benchmark]
+	fn dispute() {
+		finalise::<T>(1, Vec::new());
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), LIKED);
+
+		assert!(Disputes::<T>::contains_key(LIKED));
+	}
+
+	/// An upheld dispute, slashing the `m` members that attested the wrong value.
+	#[benchmark]
+	fn resolve_dispute(m: Linear<0, { T::MaxMembers::get() }>) -> Result<(), BenchmarkError> {
+		finalise::<T>(1, bonded_members::<T>(m));
+		let disputer: T::AccountId = whitelisted_caller();
+		fund::<T>(&disputer);
+		Pallet::<T>::dispute(RawOrigin::Signed(disputer).into(), LIKED).unwrap();
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, LIKED, Some(0));
+
+		assert_eq!(Pallet::<T>::attested(&LIKED), Some(0));
+		assert!(Members::<T>::get().is_empty());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn slash_member() -> Result<(), BenchmarkError> {
+		let member = bonded_members::<T>(T::MaxMembers::get()).remove(0);
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, member.clone());
+
+		assert!(!Members::<T>::get().contains(&member));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn kick() {
+		bonded_members::<T>(T::MaxMembers::get() - 1);
+		// `Config::Candidates` cannot drop accounts, so the former candidate is seated directly
+		let member: T::AccountId = account("former", 0, 0);
+		Members::<T>::mutate(|members| {
+			let index = members.binary_search(&member).unwrap_err();
+			members.try_insert(index, member.clone()).unwrap();
+		});
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), member.clone());
+
+		assert!(Unbonding::<T>::contains_key(&member));
+	}
+
+	#[benchmark]
+	fn clear_slashed() -> Result<(), BenchmarkError> {
+		let member: T::AccountId = account("member", 0, 0);
+		Slashed::<T>::insert(&member, ());
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, member.clone());
+
+		assert!(!Slashed::<T>::contains_key(&member));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_submission() {
+		let member: T::AccountId = account("member", 0, 0);
+		let now = frame_system::Pallet::<T>::block_number();
+		Submissions::<T>::insert(LIKED, &member, Submission { value: 1, submitted_at: now });
+		frame_system::Pallet::<T>::set_block_number(
+			now.saturating_add(T::SubmissionLifetime::get()) + 1u32.into(),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), LIKED, member.clone());
+
+		assert!(!Submissions::<T>::contains_key(LIKED, &member));
+	}
+
+	impl_benchmark_test_suite!(SocialOracle, crate::mock::new_test_ext(), crate::mock::Test);
+}
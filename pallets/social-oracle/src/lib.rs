@@ -0,0 +1,586 @@
+//! Bonded oracle committee attesting to facts about Farcaster accounts.
+//!
+//! Accounts admitted by [`Config::Candidates`] join the committee by bonding
+//! [`Config::MemberBond`], and anyone can remove a member that is no longer a candidate. Members
+//! attest to the value of a [`Subject`], such as whether an FID liked a cast or how many followers
+//! it has. Once a [`Config::Threshold`] share of the current members attested the same value it is
+//! finalised, and other pallets can read it through [`Pallet::attested`]. Attestations that do not
+//! finalise within [`Config::SubmissionLifetime`] expire and can be pruned by anyone.
+//!
+//! A finalised attestation can be disputed by anyone willing to hold [`Config::DisputeDeposit`].
+//! Governance resolves the dispute: if the attestation was wrong, it is corrected and the members
+//! that attested it lose [`Config::SlashFraction`] of their bond and are expelled; otherwise the
+//! deposit of the disputer is burned. Slashed members cannot bond again until governance clears
+//! them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+// The `TryState` impl that `frame_support` generates logs failures with `map_err`, which trips
+// `manual_inspect` under `try-runtime`.
+#[cfg_attr(feature = "try-runtime", allow(clippy::manual_inspect))]
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::vec::Vec;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::{Fortitude, Precision},
+            SortedMembers,
+        },
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Perbill,
+    };
+
+    /// A Farcaster account id.
+    pub type Fid = u64;
+    /// First 20 bytes of the BLAKE3 hash of a cast message.
+    pub type CastHash = [u8; 20];
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// A fact about a Farcaster account that members attest to.
+    ///
+    /// Yes/no facts are attested as `1` or `0`.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum Subject {
+        /// Whether `fid` liked the cast `cast_hash`.
+        Liked { fid: Fid, cast_hash: CastHash },
+        /// Whether `fid` recast the cast `cast_hash`.
+        Recasted { fid: Fid, cast_hash: CastHash },
+        /// Whether `fid` follows `target`.
+        Follows { fid: Fid, target: Fid },
+        /// Number of followers of `fid`.
+        FollowerCount { fid: Fid },
+    }
+
+    /// A finalised attestation.
+    #[derive(
+        Encode,
+        Decode,
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct Attestation<T: Config> {
+        pub value: u64,
+        pub finalised_at: BlockNumberFor<T>,
+        /// Members that attested `value`; empty when it was set by governance.
+        pub attesters: BoundedVec<T::AccountId, T::MaxMembers>,
+    }
+
+    /// A member's attestation of a subject that is not finalised yet.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct Submission<BlockNumber> {
+        pub value: u64,
+        pub submitted_at: BlockNumber,
+    }
+
+    pub type SubmissionOf<T> = Submission<BlockNumberFor<T>>;
+
+    /// An open dispute over a finalised attestation.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct Dispute<AccountId, Balance> {
+        pub disputer: AccountId,
+        pub deposit: Balance,
+    }
+
+    pub type DisputeOf<T> = Dispute<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type WeightInfo: WeightInfo;
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The overarching hold reason, which must include this pallet's `HoldReason`.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Funds held from every committee member.
+        #[pallet::constant]
+        type MemberBond: Get<BalanceOf<Self>>;
+        /// Accounts allowed to join the committee, e.g. a membership pallet run by governance.
+        type Candidates: SortedMembers<Self::AccountId>;
+        /// Share of the current members that must attest the same value to finalise it.
+        #[pallet::constant]
+        type Threshold: Get<Perbill>;
+        #[pallet::constant]
+        type MaxMembers: Get<u32>;
+        /// Blocks a leaving or expelled member waits before their bond is released, so that
+        /// their attestations can still be disputed.
+        #[pallet::constant]
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+        /// Blocks after which an attestation that did not finalise expires. Expired attestations
+        /// no longer count towards the threshold.
+        #[pallet::constant]
+        type SubmissionLifetime: Get<BlockNumberFor<Self>>;
+        /// Funds held from the account that disputes an attestation.
+        #[pallet::constant]
+        type DisputeDeposit: Get<BalanceOf<Self>>;
+        /// Share of the bond burned from a member who attested a wrong value.
+        #[pallet::constant]
+        type SlashFraction: Get<Perbill>;
+        /// Origin allowed to resolve disputes and slash members.
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    /// Reasons for the pallet to hold funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Bond of a committee member.
+        MemberBond,
+        /// Deposit of an open dispute.
+        DisputeDeposit,
+    }
+
+    /// Current committee members, sorted.
+    #[pallet::storage]
+    #[pallet::getter(fn members)]
+    pub type Members<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxMembers>, ValueQuery>;
+
+    /// Funds held from each member or former member that has not withdrawn them yet.
+    #[pallet::storage]
+    #[pallet::getter(fn bonds)]
+    pub type Bonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+    /// Block from which each former member can withdraw their bond.
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding)]
+    pub type Unbonding<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Values attested by each member for subjects that are not finalised yet.
+    #[pallet::storage]
+    #[pallet::getter(fn submissions)]
+    pub type Submissions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Subject,
+        Blake2_128Concat,
+        T::AccountId,
+        SubmissionOf<T>,
+        OptionQuery,
+    >;
+
+    /// Accounts slashed for false attestations, which cannot bond again until governance clears
+    /// them.
+    #[pallet::storage]
+    #[pallet::getter(fn slashed)]
+    pub type Slashed<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// The latest finalised attestation of each subject.
+    #[pallet::storage]
+    #[pallet::getter(fn attestations)]
+    pub type Attestations<T: Config> =
+        StorageMap<_, Blake2_128Concat, Subject, Attestation<T>, OptionQuery>;
+
+    /// Open disputes, by subject.
+    #[pallet::storage]
+    #[pallet::getter(fn disputes)]
+    pub type Disputes<T: Config> =
+        StorageMap<_, Blake2_128Concat, Subject, DisputeOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// (member, bond)
+        MemberBonded(T::AccountId, BalanceOf<T>),
+        /// (member, block from which the bond can be withdrawn)
+        MemberUnbonding(T::AccountId, BlockNumberFor<T>),
+        /// (former member, released bond)
+        BondWithdrawn(T::AccountId, BalanceOf<T>),
+        /// (subject, member, value)
+        Attested(Subject, T::AccountId, u64),
+        /// Members attested different values for a subject: (subject, value, earlier value).
+        ConflictingAttestation(Subject, u64, u64),
+        /// (subject, value)
+        AttestationFinalised(Subject, u64),
+        /// (subject, disputer)
+        AttestationDisputed(Subject, T::AccountId),
+        /// (subject, whether the attestation was wrong, corrected value)
+        DisputeResolved(Subject, bool, Option<u64>),
+        /// (member, burned amount)
+        MemberSlashed(T::AccountId, BalanceOf<T>),
+        /// A slashed account may bond again.
+        SlashCleared(T::AccountId),
+        /// An attestation that did not finalise in time was removed: (subject, member).
+        SubmissionExpired(Subject, T::AccountId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotMember,
+        AlreadyMember,
+        /// The account is not one of [`Config::Candidates`].
+        NotCandidate,
+        /// The member is still one of [`Config::Candidates`].
+        StillCandidate,
+        TooManyMembers,
+        /// The bond of the account is still unbonding or was never withdrawn.
+        StillBonded,
+        NotUnbonding,
+        /// The unbonding period has not passed yet.
+        BondLocked,
+        /// The member already attested this subject in the current round.
+        AlreadyAttested,
+        NotFinalised,
+        /// The subject has an open dispute.
+        Disputed,
+        NoDispute,
+        /// The account was slashed and governance has not cleared it yet.
+        Slashed,
+        NotSlashed,
+        NoSubmission,
+        /// The attestation is younger than [`Config::SubmissionLifetime`].
+        SubmissionNotExpired,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Join the committee, holding [`Config::MemberBond`] from the caller (candidates only)
+        ///
+        /// Slashed accounts must be cleared by governance first.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::bond())]
+        pub fn bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Candidates::contains(&who), Error::<T>::NotCandidate);
+            ensure!(!Slashed::<T>::contains_key(&who), Error::<T>::Slashed);
+            ensure!(!Bonds::<T>::contains_key(&who), Error::<T>::StillBonded);
+
+            let mut members = Members::<T>::get();
+            let Err(index) = members.binary_search(&who) else {
+                return Err(Error::<T>::AlreadyMember.into());
+            };
+            members
+                .try_insert(index, who.clone())
+                .map_err(|_| Error::<T>::TooManyMembers)?;
+
+            let bond = T::MemberBond::get();
+            T::Currency::hold(&HoldReason::MemberBond.into(), &who, bond)?;
+            Bonds::<T>::insert(&who, bond);
+            Members::<T>::put(members);
+            Self::deposit_event(Event::MemberBonded(who, bond));
+            Ok(())
+        }
+
+        /// Leave the committee; the bond can be withdrawn after [`Config::UnbondingPeriod`]
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::unbond())]
+        pub fn unbond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::remove_member(&who), Error::<T>::NotMember);
+            Ok(())
+        }
+
+        /// Release the bond of a former member once its unbonding period has passed
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::withdraw_bond())]
+        pub fn withdraw_bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let unlocked_at = Unbonding::<T>::get(&who).ok_or(Error::<T>::NotUnbonding)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unlocked_at,
+                Error::<T>::BondLocked
+            );
+
+            let bond = Bonds::<T>::take(&who).unwrap_or_else(Zero::zero);
+            Unbonding::<T>::remove(&who);
+            T::Currency::release(
+                &HoldReason::MemberBond.into(),
+                &who,
+                bond,
+                Precision::BestEffort,
+            )?;
+            Self::deposit_event(Event::BondWithdrawn(who, bond));
+            Ok(())
+        }
+
+        /// Attest that `subject` has `value` (members only)
+        ///
+        /// The value is finalised once a [`Config::Threshold`] share of the current members
+        /// attested it within [`Config::SubmissionLifetime`]. A finalised subject can be attested
+        /// again to update its value, and an expired attestation can be replaced.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::attest())]
+        pub fn attest(origin: OriginFor<T>, subject: Subject, value: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let members = Members::<T>::get();
+            ensure!(members.binary_search(&who).is_ok(), Error::<T>::NotMember);
+            ensure!(!Disputes::<T>::contains_key(subject), Error::<T>::Disputed);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                Submissions::<T>::get(subject, &who)
+                    .is_none_or(|submission| Self::submission_expired(&submission, now)),
+                Error::<T>::AlreadyAttested
+            );
+
+            let mut attesters = Vec::new();
+            for (member, other) in Submissions::<T>::iter_prefix(subject) {
+                if member == who || Self::submission_expired(&other, now) {
+                    continue;
+                }
+                if other.value != value {
+                    Self::deposit_event(Event::ConflictingAttestation(subject, value, other.value));
+                } else if members.binary_search(&member).is_ok() {
+                    // Members that left since they attested no longer count
+                    attesters.push(member);
+                }
+            }
+            Submissions::<T>::insert(
+                subject,
+                &who,
+                Submission {
+                    value,
+                    submitted_at: now,
+                },
+            );
+            attesters.push(who.clone());
+            Self::deposit_event(Event::Attested(subject, who, value));
+
+            let threshold = T::Threshold::get().mul_ceil(members.len() as u32).max(1);
+            if attesters.len() as u32 >= threshold {
+                attesters.sort();
+                Attestations::<T>::insert(
+                    subject,
+                    Attestation {
+                        value,
+                        finalised_at: now,
+                        attesters: BoundedVec::truncate_from(attesters),
+                    },
+                );
+                let _ = Submissions::<T>::clear_prefix(subject, T::MaxMembers::get(), None);
+                Self::deposit_event(Event::AttestationFinalised(subject, value));
+            }
+            Ok(())
+        }
+
+        /// Dispute the finalised attestation of `subject`, holding [`Config::DisputeDeposit`]
+        ///
+        /// The attestation is not reported to other pallets until the dispute is resolved.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::dispute())]
+        pub fn dispute(origin: OriginFor<T>, subject: Subject) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Attestations::<T>::contains_key(subject),
+                Error::<T>::NotFinalised
+            );
+            ensure!(!Disputes::<T>::contains_key(subject), Error::<T>::Disputed);
+
+            let deposit = T::DisputeDeposit::get();
+            T::Currency::hold(&HoldReason::DisputeDeposit.into(), &who, deposit)?;
+            Disputes::<T>::insert(
+                subject,
+                Dispute {
+                    disputer: who.clone(),
+                    deposit,
+                },
+            );
+            Self::deposit_event(Event::AttestationDisputed(subject, who));
+            Ok(())
+        }
+
+        /// Resolve the dispute over `subject` with its true value (governance only)
+        ///
+        /// If `value` differs from the finalised one, the attestation is replaced (or removed if
+        /// `value` is `None`), the deposit is returned and every member that attested the wrong
+        /// value is slashed and expelled. Otherwise the deposit is burned.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::resolve_dispute(T::MaxMembers::get()))]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            subject: Subject,
+            value: Option<u64>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            let dispute = Disputes::<T>::take(subject).ok_or(Error::<T>::NoDispute)?;
+            let attestation = Attestations::<T>::get(subject).ok_or(Error::<T>::NotFinalised)?;
+
+            let upheld = value != Some(attestation.value);
+            if upheld {
+                T::Currency::release(
+                    &HoldReason::DisputeDeposit.into(),
+                    &dispute.disputer,
+                    dispute.deposit,
+                    Precision::BestEffort,
+                )?;
+                for attester in &attestation.attesters {
+                    Self::slash(attester);
+                }
+                match value {
+                    Some(value) => Attestations::<T>::insert(
+                        subject,
+                        Attestation {
+                            value,
+                            finalised_at: frame_system::Pallet::<T>::block_number(),
+                            attesters: BoundedVec::new(),
+                        },
+                    ),
+                    None => Attestations::<T>::remove(subject),
+                }
+            } else {
+                T::Currency::burn_held(
+                    &HoldReason::DisputeDeposit.into(),
+                    &dispute.disputer,
+                    dispute.deposit,
+                    Precision::BestEffort,
+                    Fortitude::Force,
+                )?;
+            }
+            Self::deposit_event(Event::DisputeResolved(subject, upheld, value));
+            Ok(())
+        }
+
+        /// Slash and expel `member` for attestations shown to be false outside a dispute
+        /// (governance only)
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::slash_member())]
+        pub fn slash_member(origin: OriginFor<T>, member: T::AccountId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(Bonds::<T>::contains_key(&member), Error::<T>::NotMember);
+            Self::slash(&member);
+            Ok(())
+        }
+
+        /// Remove `member` from the committee once it is no longer one of
+        /// [`Config::Candidates`]; its bond unbonds as if it had left
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::kick())]
+        pub fn kick(origin: OriginFor<T>, member: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                !T::Candidates::contains(&member),
+                Error::<T>::StillCandidate
+            );
+            ensure!(Self::remove_member(&member), Error::<T>::NotMember);
+            Ok(())
+        }
+
+        /// Allow a slashed account to bond again (governance only)
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::clear_slashed())]
+        pub fn clear_slashed(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(Slashed::<T>::take(&who).is_some(), Error::<T>::NotSlashed);
+            Self::deposit_event(Event::SlashCleared(who));
+            Ok(())
+        }
+
+        /// Remove an attestation of `member` that expired before `subject` was finalised
+        ///
+        /// Anyone can call this to clean up attestations that never reached the threshold.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::prune_submission())]
+        pub fn prune_submission(
+            origin: OriginFor<T>,
+            subject: Subject,
+            member: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let submission =
+                Submissions::<T>::get(subject, &member).ok_or(Error::<T>::NoSubmission)?;
+            ensure!(
+                Self::submission_expired(&submission, frame_system::Pallet::<T>::block_number()),
+                Error::<T>::SubmissionNotExpired
+            );
+
+            Submissions::<T>::remove(subject, &member);
+            Self::deposit_event(Event::SubmissionExpired(subject, member));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Finalised value of `subject`, unless it is disputed.
+        pub fn attested(subject: &Subject) -> Option<u64> {
+            if Disputes::<T>::contains_key(subject) {
+                return None;
+            }
+            Attestations::<T>::get(subject).map(|attestation| attestation.value)
+        }
+
+        /// Whether `submission` was made more than [`Config::SubmissionLifetime`] blocks before
+        /// `now`.
+        fn submission_expired(submission: &SubmissionOf<T>, now: BlockNumberFor<T>) -> bool {
+            now > submission
+                .submitted_at
+                .saturating_add(T::SubmissionLifetime::get())
+        }
+
+        /// Remove `who` from the committee and start unbonding. Returns whether it was a member.
+        fn remove_member(who: &T::AccountId) -> bool {
+            let removed = Members::<T>::mutate(|members| match members.binary_search(who) {
+                Ok(index) => {
+                    members.remove(index);
+                    true
+                }
+                Err(_) => false,
+            });
+            if removed {
+                let unlocked_at = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::UnbondingPeriod::get());
+                Unbonding::<T>::insert(who, unlocked_at);
+                Self::deposit_event(Event::MemberUnbonding(who.clone(), unlocked_at));
+            }
+            removed
+        }
+
+        /// Burn [`Config::SlashFraction`] of the bond of `who`, expel it from the committee and
+        /// bar it from bonding again. Former members are slashed too while their bond is
+        /// unbonding.
+        fn slash(who: &T::AccountId) {
+            let Some(bond) = Bonds::<T>::get(who) else {
+                return;
+            };
+            let amount = T::SlashFraction::get() * bond;
+            let burned = T::Currency::burn_held(
+                &HoldReason::MemberBond.into(),
+                who,
+                amount,
+                Precision::BestEffort,
+                Fortitude::Force,
+            )
+            .unwrap_or_else(|_| Zero::zero());
+            Bonds::<T>::insert(who, bond.saturating_sub(burned));
+            Slashed::<T>::insert(who, ());
+            Self::remove_member(who);
+            Self::deposit_event(Event::MemberSlashed(who.clone(), burned));
+        }
+    }
+
+    impl<T: Config> SortedMembers<T::AccountId> for Pallet<T> {
+        fn sorted_members() -> Vec<T::AccountId> {
+            Members::<T>::get().into_inner()
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use frame_support::{derive_impl, parameter_types, traits::SortedMembers};
+use frame_system::{mocking::MockBlock, EnsureRoot};
+use sp_runtime::{
+	traits::{ConstU32, ConstU64},
+	BuildStorage, Perbill,
+};
+
+pub type AccountId = u64;
+pub type Balance = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const DAVE: AccountId = 4;
+
+/// Balance every test account starts with.
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+pub const BOND: Balance = 1_000;
+pub const DEPOSIT: Balance = 100;
+pub const UNBONDING_PERIOD: u64 = 10;
+pub const SUBMISSION_LIFETIME: u64 = 20;
+
+// Configure a mock runtime to test the pallet.
+#[frame_support::runtime]
+mod test_runtime {
+	#[runtime::runtime]
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask
+	)]
+	pub struct Test;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system;
+	#[runtime::pallet_index(1)]
+	pub type Balances = pallet_balances;
+	#[runtime::pallet_index(2)]
+	pub type SocialOracle = crate;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = MockBlock<Test>;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub const SlashFraction: Perbill = Perbill::from_percent(50);
+	pub const Threshold: Perbill = Perbill::from_percent(60);
+	pub static Candidates: Vec<AccountId> = vec![ALICE, BOB, CHARLIE, DAVE];
+}
+
+pub struct OracleCandidates;
+impl SortedMembers<AccountId> for OracleCandidates {
+	fn sorted_members() -> Vec<AccountId> {
+		Candidates::get()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &AccountId) {
+		let mut candidates = Candidates::get();
+		candidates.push(*who);
+		candidates.sort();
+		Candidates::set(candidates);
+	}
+}
+
+impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MemberBond = ConstU64<BOND>;
+	type Candidates = OracleCandidates;
+	type Threshold = Threshold;
+	type MaxMembers = ConstU32<3>;
+	type UnbondingPeriod = ConstU64<UNBONDING_PERIOD>;
+	type SubmissionLifetime = ConstU64<SUBMISSION_LIFETIME>;
+	type DisputeDeposit = ConstU64<DEPOSIT>;
+	type SlashFraction = SlashFraction;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(BOB, INITIAL_BALANCE),
+			(CHARLIE, INITIAL_BALANCE),
+			(DAVE, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
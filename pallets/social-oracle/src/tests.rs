@@ -0,0 +1,302 @@
+use crate::{mock::*, Error, Event, HoldReason, Subject};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, SortedMembers},
+};
+
+const CAST: [u8; 20] = [7; 20];
+const LIKED: Subject = Subject::Liked { fid: 42, cast_hash: CAST };
+
+fn bond(who: AccountId) {
+	assert_ok!(SocialOracle::bond(RuntimeOrigin::signed(who)));
+}
+
+fn attest(who: AccountId, subject: Subject, value: u64) {
+	assert_ok!(SocialOracle::attest(RuntimeOrigin::signed(who), subject, value));
+}
+
+fn held(reason: HoldReason, who: AccountId) -> Balance {
+	Balances::balance_on_hold(&reason.into(), &who)
+}
+
+#[test]
+fn bonding_and_unbonding() {
+	new_test_ext().execute_with(|| {
+		bond(CHARLIE);
+		bond(ALICE);
+		System::assert_last_event(Event::MemberBonded(ALICE, BOND).into());
+		assert_noop!(SocialOracle::bond(RuntimeOrigin::signed(ALICE)), Error::<Test>::StillBonded);
+		assert_eq!(SocialOracle::sorted_members(), vec![ALICE, CHARLIE]);
+		assert_eq!(held(HoldReason::MemberBond, ALICE), BOND);
+
+		bond(BOB);
+		assert_noop!(
+			SocialOracle::bond(RuntimeOrigin::signed(DAVE)),
+			Error::<Test>::TooManyMembers
+		);
+
+		assert_ok!(SocialOracle::unbond(RuntimeOrigin::signed(ALICE)));
+		assert_noop!(SocialOracle::unbond(RuntimeOrigin::signed(ALICE)), Error::<Test>::NotMember);
+		assert_eq!(SocialOracle::members().into_inner(), vec![BOB, CHARLIE]);
+		assert_noop!(
+			SocialOracle::withdraw_bond(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::BondLocked
+		);
+		assert_noop!(SocialOracle::bond(RuntimeOrigin::signed(ALICE)), Error::<Test>::StillBonded);
+
+		System::set_block_number(1 + UNBONDING_PERIOD);
+		assert_ok!(SocialOracle::withdraw_bond(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(Event::BondWithdrawn(ALICE, BOND).into());
+		assert_eq!(held(HoldReason::MemberBond, ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert_noop!(
+			SocialOracle::withdraw_bond(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NotUnbonding
+		);
+	});
+}
+
+#[test]
+fn attestation_finalises_once_threshold_agrees() {
+	new_test_ext().execute_with(|| {
+		bond(ALICE);
+		bond(BOB);
+		bond(CHARLIE);
+		assert_noop!(
+			SocialOracle::attest(RuntimeOrigin::signed(DAVE), LIKED, 1),
+			Error::<Test>::NotMember
+		);
+
+		attest(ALICE, LIKED, 1);
+		assert_noop!(
+			SocialOracle::attest(RuntimeOrigin::signed(ALICE), LIKED, 1),
+			Error::<Test>::AlreadyAttested
+		);
+		attest(BOB, LIKED, 0);
+		System::assert_has_event(Event::ConflictingAttestation(LIKED, 0, 1).into());
+		assert_eq!(SocialOracle::attested(&LIKED), None);
+
+		attest(CHARLIE, LIKED, 1);
+		System::assert_last_event(Event::AttestationFinalised(LIKED, 1).into());
+		assert_eq!(SocialOracle::attested(&LIKED), Some(1));
+		assert_eq!(
+			SocialOracle::attestations(LIKED).unwrap().attesters.into_inner(),
+			vec![ALICE, CHARLIE]
+		);
+		assert_eq!(SocialOracle::submissions(LIKED, BOB), None);
+
+		// A new round updates the value
+		let followers = Subject::FollowerCount { fid: 42 };
+		attest(ALICE, followers, 10);
+		attest(BOB, followers, 10);
+		attest(BOB, followers, 12);
+		attest(CHARLIE, followers, 12);
+		assert_eq!(SocialOracle::attested(&followers), Some(12));
+	});
+}
+
+#[test]
+fn attestations_expire_unless_finalised() {
+	new_test_ext().execute_with(|| {
+		bond(ALICE);
+		bond(BOB);
+		bond(CHARLIE);
+		attest(ALICE, LIKED, 1);
+		assert_noop!(
+			SocialOracle::prune_submission(RuntimeOrigin::signed(DAVE), LIKED, ALICE),
+			Error::<Test>::SubmissionNotExpired
+		);
+
+		// An expired attestation no longer counts towards the threshold
+		System::set_block_number(2 + SUBMISSION_LIFETIME);
+		attest(BOB, LIKED, 1);
+		assert_eq!(SocialOracle::attested(&LIKED), None);
+
+		assert_ok!(SocialOracle::prune_submission(RuntimeOrigin::signed(DAVE), LIKED, ALICE));
+		System::assert_last_event(Event::SubmissionExpired(LIKED, ALICE).into());
+		assert_eq!(SocialOracle::submissions(LIKED, ALICE), None);
+		assert_noop!(
+			SocialOracle::prune_submission(RuntimeOrigin::signed(DAVE), LIKED, ALICE),
+			Error::<Test>::NoSubmission
+		);
+
+		attest(ALICE, LIKED, 1);
+		assert_eq!(SocialOracle::attested(&LIKED), Some(1));
+	});
+}
+
+#[test]
+fn former_members_do_not_count() {
+	new_test_ext().execute_with(|| {
+		bond(ALICE);
+		bond(BOB);
+		bond(CHARLIE);
+		attest(ALICE, LIKED, 1);
+		assert_ok!(SocialOracle::unbond(RuntimeOrigin::signed(ALICE)));
+		attest(BOB, LIKED, 1);
+		assert_eq!(SocialOracle::attested(&LIKED), None);
+
+		attest(CHARLIE, LIKED, 1);
+		assert_eq!(SocialOracle::attested(&LIKED), Some(1));
+	});
+}
+
+#[test]
+fn threshold_follows_the_committee_size() {
+	new_test_ext().execute_with(|| {
+		// 60% of one member
+		bond(ALICE);
+		attest(ALICE, LIKED, 1);
+		assert_eq!(SocialOracle::attested(&LIKED), Some(1));
+
+		// 60% of three members
+		bond(BOB);
+		bond(CHARLIE);
+		let followers = Subject::FollowerCount { fid: 42 };
+		attest(ALICE, followers, 10);
+		assert_eq!(SocialOracle::attested(&followers), None);
+		attest(BOB, followers, 10);
+		assert_eq!(SocialOracle::attested(&followers), Some(10));
+	});
+}
+
+#[test]
+fn only_candidates_join_the_committee() {
+	new_test_ext().execute_with(|| {
+		Candidates::set(vec![ALICE, BOB]);
+		assert_noop!(
+			SocialOracle::bond(RuntimeOrigin::signed(CHARLIE)),
+			Error::<Test>::NotCandidate
+		);
+		bond(ALICE);
+		bond(BOB);
+		assert_noop!(
+			SocialOracle::kick(RuntimeOrigin::signed(CHARLIE), ALICE),
+			Error::<Test>::StillCandidate
+		);
+
+		// Members that lose their candidacy can be removed by anyone
+		Candidates::set(vec![BOB]);
+		assert_ok!(SocialOracle::kick(RuntimeOrigin::signed(CHARLIE), ALICE));
+		System::assert_last_event(Event::MemberUnbonding(ALICE, 1 + UNBONDING_PERIOD).into());
+		assert_eq!(SocialOracle::members().into_inner(), vec![BOB]);
+		assert_eq!(held(HoldReason::MemberBond, ALICE), BOND);
+		assert_noop!(
+			SocialOracle::kick(RuntimeOrigin::signed(CHARLIE), ALICE),
+			Error::<Test>::NotMember
+		);
+
+		System::set_block_number(1 + UNBONDING_PERIOD);
+		assert_ok!(SocialOracle::withdraw_bond(RuntimeOrigin::signed(ALICE)));
+		assert_noop!(SocialOracle::bond(RuntimeOrigin::signed(ALICE)), Error::<Test>::NotCandidate);
+	});
+}
+
+#[test]
+fn upheld_dispute_corrects_and_slashes() {
+	new_test_ext().execute_with(|| {
+		bond(ALICE);
+		bond(BOB);
+		bond(CHARLIE);
+		assert_noop!(
+			SocialOracle::dispute(RuntimeOrigin::signed(DAVE), LIKED),
+			Error::<Test>::NotFinalised
+		);
+		attest(ALICE, LIKED, 1);
+		attest(BOB, LIKED, 1);
+
+		assert_ok!(SocialOracle::dispute(RuntimeOrigin::signed(DAVE), LIKED));
+		assert_eq!(held(HoldReason::DisputeDeposit, DAVE), DEPOSIT);
+		assert_noop!(
+			SocialOracle::dispute(RuntimeOrigin::signed(CHARLIE), LIKED),
+			Error::<Test>::Disputed
+		);
+		assert_noop!(
+			SocialOracle::attest(RuntimeOrigin::signed(CHARLIE), LIKED, 0),
+			Error::<Test>::Disputed
+		);
+		// Disputed attestations are not reported
+		assert_eq!(SocialOracle::attested(&LIKED), None);
+
+		assert_noop!(
+			SocialOracle::resolve_dispute(RuntimeOrigin::signed(CHARLIE), LIKED, Some(0)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(SocialOracle::resolve_dispute(RuntimeOrigin::root(), LIKED, Some(0)));
+		System::assert_last_event(Event::DisputeResolved(LIKED, true, Some(0)).into());
+		System::assert_has_event(Event::MemberSlashed(ALICE, BOND / 2).into());
+		assert_eq!(SocialOracle::attested(&LIKED), Some(0));
+		assert_eq!(held(HoldReason::DisputeDeposit, DAVE), 0);
+		assert_eq!(Balances::free_balance(DAVE), INITIAL_BALANCE);
+
+		assert_eq!(SocialOracle::members().into_inner(), vec![CHARLIE]);
+		assert_eq!(SocialOracle::bonds(ALICE), Some(BOND / 2));
+		assert_eq!(held(HoldReason::MemberBond, BOB), BOND / 2);
+		assert_eq!(Balances::total_issuance(), 4 * INITIAL_BALANCE - BOND);
+
+		System::set_block_number(1 + UNBONDING_PERIOD);
+		assert_ok!(SocialOracle::withdraw_bond(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - BOND / 2);
+		assert_noop!(SocialOracle::bond(RuntimeOrigin::signed(ALICE)), Error::<Test>::Slashed);
+		assert_noop!(
+			SocialOracle::resolve_dispute(RuntimeOrigin::root(), LIKED, None),
+			Error::<Test>::NoDispute
+		);
+	});
+}
+
+#[test]
+fn rejected_dispute_burns_deposit() {
+	new_test_ext().execute_with(|| {
+		bond(ALICE);
+		bond(BOB);
+		attest(ALICE, LIKED, 1);
+		attest(BOB, LIKED, 1);
+		assert_ok!(SocialOracle::dispute(RuntimeOrigin::signed(DAVE), LIKED));
+
+		assert_ok!(SocialOracle::resolve_dispute(RuntimeOrigin::root(), LIKED, Some(1)));
+		System::assert_last_event(Event::DisputeResolved(LIKED, false, Some(1)).into());
+		assert_eq!(Balances::free_balance(DAVE), INITIAL_BALANCE - DEPOSIT);
+		assert_eq!(held(HoldReason::DisputeDeposit, DAVE), 0);
+		assert_eq!(SocialOracle::attested(&LIKED), Some(1));
+		assert_eq!(SocialOracle::members().into_inner(), vec![ALICE, BOB]);
+	});
+}
+
+#[test]
+fn governance_can_slash_members() {
+	new_test_ext().execute_with(|| {
+		bond(ALICE);
+		assert_noop!(
+			SocialOracle::slash_member(RuntimeOrigin::root(), BOB),
+			Error::<Test>::NotMember
+		);
+		assert_ok!(SocialOracle::slash_member(RuntimeOrigin::root(), ALICE));
+		assert_eq!(SocialOracle::members().len(), 0);
+		assert_eq!(held(HoldReason::MemberBond, ALICE), BOND / 2);
+		assert_eq!(SocialOracle::unbonding(ALICE), Some(1 + UNBONDING_PERIOD));
+	});
+}
+
+#[test]
+fn slashed_members_bond_again_once_cleared() {
+	new_test_ext().execute_with(|| {
+		bond(ALICE);
+		assert_ok!(SocialOracle::slash_member(RuntimeOrigin::root(), ALICE));
+		System::set_block_number(1 + UNBONDING_PERIOD);
+		assert_ok!(SocialOracle::withdraw_bond(RuntimeOrigin::signed(ALICE)));
+		assert_noop!(SocialOracle::bond(RuntimeOrigin::signed(ALICE)), Error::<Test>::Slashed);
+
+		assert_noop!(
+			SocialOracle::clear_slashed(RuntimeOrigin::signed(BOB), ALICE),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			SocialOracle::clear_slashed(RuntimeOrigin::root(), BOB),
+			Error::<Test>::NotSlashed
+		);
+		assert_ok!(SocialOracle::clear_slashed(RuntimeOrigin::root(), ALICE));
+		System::assert_last_event(Event::SlashCleared(ALICE).into());
+		bond(ALICE);
+	});
+}
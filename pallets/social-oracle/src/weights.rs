@@ -0,0 +1,193 @@
+//! Weights for pallet_social_oracle
+//!
+//! Estimated from the storage accesses of each call, not measured. Replace this file with the
+//! output of the command below, run on reference hardware.
+
+// Command:
+// ./target/release/parachain-template-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_social_oracle
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/social-oracle/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_social_oracle.
+pub trait WeightInfo {
+	fn bond() -> Weight;
+	fn unbond() -> Weight;
+	fn withdraw_bond() -> Weight;
+	fn attest() -> Weight;
+	fn dispute() -> Weight;
+	fn resolve_dispute(m: u32, ) -> Weight;
+	fn slash_member() -> Weight;
+	fn kick() -> Weight;
+	fn clear_slashed() -> Weight;
+	fn prune_submission() -> Weight;
+}
+
+/// Weights for pallet_social_oracle using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: OracleCommittee Members (r:1 w:0)
+	/// Storage: SocialOracle Slashed (r:1 w:0)
+	/// Storage: SocialOracle Bonds (r:1 w:1)
+	/// Storage: SocialOracle Members (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	fn bond() -> Weight {
+		Weight::from_parts(47_000_000, 4_000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: SocialOracle Members (r:1 w:1)
+	/// Storage: SocialOracle Unbonding (r:0 w:1)
+	fn unbond() -> Weight {
+		Weight::from_parts(20_000_000, 2_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: SocialOracle Unbonding (r:1 w:1)
+	/// Storage: SocialOracle Bonds (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	fn withdraw_bond() -> Weight {
+		Weight::from_parts(40_000_000, 4_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: SocialOracle Members (r:1 w:0)
+	/// Storage: SocialOracle Disputes (r:1 w:0)
+	/// Storage: SocialOracle Submissions (r:16 w:16)
+	/// Storage: SocialOracle Attestations (r:0 w:1)
+	fn attest() -> Weight {
+		Weight::from_parts(60_000_000, 20_000)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	/// Storage: SocialOracle Attestations (r:1 w:0)
+	/// Storage: SocialOracle Disputes (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	fn dispute() -> Weight {
+		Weight::from_parts(40_000_000, 4_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: SocialOracle Disputes (r:1 w:1)
+	/// Storage: SocialOracle Attestations (r:1 w:1)
+	/// Storage: Balances Holds (r:17 w:17)
+	/// Storage: SocialOracle Bonds (r:16 w:16)
+	/// Storage: SocialOracle Members (r:16 w:16)
+	/// Storage: SocialOracle Slashed (r:0 w:16)
+	/// Storage: SocialOracle Unbonding (r:0 w:16)
+	/// The range of component `m` is `[0, 16]`.
+	fn resolve_dispute(m: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 6_000)
+			.saturating_add(Weight::from_parts(37_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(m.into()))
+	}
+	/// Storage: SocialOracle Bonds (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: SocialOracle Members (r:1 w:1)
+	/// Storage: SocialOracle Slashed (r:0 w:1)
+	/// Storage: SocialOracle Unbonding (r:0 w:1)
+	fn slash_member() -> Weight {
+		Weight::from_parts(47_000_000, 4_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: OracleCommittee Members (r:1 w:0)
+	/// Storage: SocialOracle Members (r:1 w:1)
+	/// Storage: SocialOracle Unbonding (r:0 w:1)
+	fn kick() -> Weight {
+		Weight::from_parts(22_000_000, 2_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: SocialOracle Slashed (r:1 w:1)
+	fn clear_slashed() -> Weight {
+		Weight::from_parts(15_000_000, 2_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: SocialOracle Submissions (r:1 w:1)
+	fn prune_submission() -> Weight {
+		Weight::from_parts(17_000_000, 2_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn bond() -> Weight {
+		Weight::from_parts(47_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn unbond() -> Weight {
+		Weight::from_parts(20_000_000, 2_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn withdraw_bond() -> Weight {
+		Weight::from_parts(40_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn attest() -> Weight {
+		Weight::from_parts(60_000_000, 20_000)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	fn dispute() -> Weight {
+		Weight::from_parts(40_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn resolve_dispute(m: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 6_000)
+			.saturating_add(Weight::from_parts(37_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(m.into()))
+	}
+	fn slash_member() -> Weight {
+		Weight::from_parts(47_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn kick() -> Weight {
+		Weight::from_parts(22_000_000, 2_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn clear_slashed() -> Weight {
+		Weight::from_parts(15_000_000, 2_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn prune_submission() -> Weight {
+		Weight::from_parts(17_000_000, 2_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
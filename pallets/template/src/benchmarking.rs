@@ -1,34 +1,808 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-nft-farcaster-frames
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
 use frame_benchmarking::v2::*;
+use frame_support::sp_runtime::traits::{Bounded, Saturating};
+use frame_support::{
+	pallet_prelude::BoundedVec,
+	traits::{
+		fungible::{Inspect, Mutate},
+		EnsureOrigin, Get, Hooks,
+	},
+};
+use frame_system::RawOrigin;
+use sp_core::ed25519;
+use sp_std::{vec, vec::Vec};
+
+/// Longest collection or item metadata, in bytes.
+const MAX_METADATA: u32 = 256;
+/// Max supply of the benchmarked collections.
+const MAX_ITEMS: u32 = 256;
+/// FID the benchmark accounts are linked to.
+const FID: Fid = 42;
+/// Cast the gated collections ask engagement with.
+const CAST: CastHash = [7; 20];
+
+/// Like of the cast `0xa0b1…a8b9` by FID 42 on mainnet, signed by [`REACTION_SIGNER`].
+///
+/// Taken from the `like` test vector of farcaster-primitives.
+const REACTION: [u8; 169] = hex_literal::hex!("0a290803102a1880efb93420013a1c0801121808031214a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b91214175ad6037b3b2101dc029b9d62e333fe8be410c218012240108902c70bc86ab9b9052cda1a4a8f2b2dbe74fc5cae707cddc300205d68d7e9498fece510c884f556f647da7ce8d76165f4674da145d67d7cc432ab5604da012801322003a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8");
+const REACTION_SIGNER: [u8; 32] =
+	hex_literal::hex!("03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8");
+
+/// Give `who` enough funds to cover any deposit or price.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::set_balance(who, BalanceOf::<T>::max_value() / 1_000u32.into());
+}
+
+/// Price items are listed and bid at, high enough for the royalty to clear the existential
+/// deposit.
+fn price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().saturating_mul(1_000u32.into())
+}
+
+fn metadata(len: u32) -> Vec<u8> {
+	vec![0u8; len as usize]
+}
+
+/// Register `artist` and have them approved.
+fn verified_artist<T: Config>(artist: &T::AccountId) {
+	fund::<T>(artist);
+	Pallet::<T>::register_artist(RawOrigin::Signed(artist.clone()).into()).unwrap();
+	let admin = T::ArtistAdminOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::approve_artist(admin, artist.clone()).unwrap();
+}
+
+/// Give `creator` an empty collection with the longest allowed metadata and the highest
+/// royalty, paid to a separate account.
+fn setup_collection<T: Config>(creator: &T::AccountId) -> CollectionId {
+	verified_artist::<T>(creator);
+	let recipient: T::AccountId = account("recipient", 0, 0);
+	fund::<T>(&recipient);
+	let collection_id = NextCollectionId::<T>::get();
+	Pallet::<T>::create_collection(
+		RawOrigin::Signed(creator.clone()).into(),
+		metadata(MAX_METADATA),
+		T::MaxRoyalty::get(),
+		Some(recipient),
+		None,
+	)
+	.unwrap();
+	collection_id
+}
+
+/// Create a listed item with the longest allowed metadata.
+fn create_item<T: Config>(creator: &T::AccountId, collection_id: CollectionId) -> ItemId {
+	let item_id = NextItemId::<T>::get(collection_id);
+	Pallet::<T>::create_nft(
+		RawOrigin::Signed(creator.clone()).into(),
+		collection_id,
+		metadata(MAX_METADATA),
+		price::<T>(),
+	)
+	.unwrap();
+	item_id
+}
+
+/// Fill an item with `MaxOffersPerItem` open offers, one from each of as many buyers.
+fn fill_offers<T: Config>(collection_id: CollectionId, item_id: ItemId) {
+	let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+	for i in 0..T::MaxOffersPerItem::get() {
+		let buyer: T::AccountId = account("buyer", i, 0);
+		fund::<T>(&buyer);
+		Pallet::<T>::make_offer(
+			RawOrigin::Signed(buyer).into(),
+			collection_id,
+			item_id,
+			price::<T>(),
+			expiry,
+			None,
+		)
+		.unwrap();
+	}
+}
+
+/// Leave an engagement check of `FID` on `CAST` pending since `requested_at`.
+fn pending_check<T: Config>(requested_at: frame_system::pallet_prelude::BlockNumberFor<T>) {
+	let requester: T::AccountId = account("requester", 0, 0);
+	PendingEngagementChecks::<T>::insert(
+		FID,
+		CAST,
+		PendingEngagementCheck { requester: requester.clone(), cast_fid: FID, requested_at },
+	);
+	PendingEngagementCheckCount::<T>::insert(requester, 1);
+}
+
+/// Put a new item of `seller` up for auction for the shortest allowed duration.
+fn auction_item<T: Config>(seller: &T::AccountId) -> (CollectionId, ItemId) {
+	let collection_id = setup_collection::<T>(seller);
+	let item_id = create_item::<T>(seller, collection_id);
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(seller.clone()).into();
+	Pallet::<T>::delist_nft(origin.clone(), collection_id, item_id).unwrap();
+	Pallet::<T>::create_auction(
+		origin,
+		collection_id,
+		item_id,
+		price::<T>(),
+		T::MinAuctionDuration::get(),
+	)
+	.unwrap();
+	(collection_id, item_id)
+}
+
+/// Gate the collection on [`CAST`], link `who` to [`FID`] and return an attestation of their
+/// engagement, so the buyer pays for the signature check.
+fn gate<T: Config>(collection_id: CollectionId, who: &T::AccountId) -> EngagementAttestationOf<T> {
+	let rule =
+		GatingRule { cast_hash: CAST, actions: EngagementActions { like: true, recast: true } };
+	CollectionGating::<T>::insert(collection_id, &rule);
+	FidOf::<T>::insert(who, FID);
+	AccountOfFid::<T>::insert(FID, who);
+
+	let attester = sp_io::crypto::ed25519_generate(KEY_TYPE, None);
+	T::BenchmarkHelper::trust_attester(attester);
+	let expiry = frame_system::Pallet::<T>::block_number();
+	let payload =
+		Pallet::<T>::engagement_payload(FID, who, &rule, expiry, EngagementNonce::<T>::get(who));
+	let signature = sp_io::crypto::ed25519_sign(KEY_TYPE, &attester, &payload).unwrap();
+	EngagementAttestation { attester, expiry, signature }
+}
+
+/// Profile with every field at its longest.
+fn max_profile(fid: Option<Fid>) -> ArtistProfile {
+	ArtistProfile {
+		display_name: metadata(MAX_NAME_LENGTH).try_into().unwrap(),
+		avatar_cid: metadata(MAX_CID_LENGTH).try_into().unwrap(),
+		links: vec![metadata(MAX_LINK_LENGTH).try_into().unwrap(); MAX_LINKS as usize]
+			.try_into()
+			.unwrap(),
+		fid,
+	}
+}
+
+/// Link `who` to `fid` and give them a profile claiming it.
+fn link_with_profile<T: Config>(who: &T::AccountId, fid: Fid) {
+	FidOf::<T>::insert(who, fid);
+	AccountOfFid::<T>::insert(fid, who);
+	ArtistProfiles::<T>::insert(who, max_profile(Some(fid)));
+	FidToArtist::<T>::insert(fid, who);
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 	#[cfg(test)]
 	use crate::pallet::Pallet as Template;
-	use frame_system::RawOrigin;
 
 	#[benchmark]
-	fn do_something() {
+	fn register_artist() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Artists::<T>::get(&caller), Some(ArtistStatus::Pending));
+	}
+
+	#[benchmark]
+	fn deregister_artist() {
 		let caller: T::AccountId = whitelisted_caller();
+		verified_artist::<T>(&caller);
+		link_with_profile::<T>(&caller, FID);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Artists::<T>::contains_key(&caller));
+		assert!(!FidToArtist::<T>::contains_key(FID));
+	}
+
+	#[benchmark]
+	fn approve_artist() -> Result<(), BenchmarkError> {
+		let artist: T::AccountId = account("artist", 0, 0);
+		fund::<T>(&artist);
+		Pallet::<T>::register_artist(RawOrigin::Signed(artist.clone()).into()).unwrap();
+		let origin = T::ArtistAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, artist.clone());
+
+		assert_eq!(Artists::<T>::get(&artist), Some(ArtistStatus::Verified));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_artist() -> Result<(), BenchmarkError> {
+		let artist: T::AccountId = account("artist", 0, 0);
+		verified_artist::<T>(&artist);
+		let origin = T::ArtistAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, artist.clone());
+
+		assert_eq!(Artists::<T>::get(&artist), Some(ArtistStatus::Suspended));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_collection(m: Linear<0, MAX_METADATA>) {
+		let caller: T::AccountId = whitelisted_caller();
+		verified_artist::<T>(&caller);
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let collection_id = NextCollectionId::<T>::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			metadata(m),
+			T::MaxRoyalty::get(),
+			Some(recipient),
+			Some(MAX_ITEMS),
+		);
+
+		assert!(Collections::<T>::contains_key(collection_id));
+		assert_eq!(ArtistCollectionCount::<T>::get(&caller), 1);
+	}
+
+	#[benchmark]
+	fn update_collection_metadata(m: Linear<0, MAX_METADATA>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, vec![1u8; m as usize]);
+
+		assert_eq!(Collections::<T>::get(collection_id).unwrap().metadata.len(), m as usize);
+	}
+
+	#[benchmark]
+	fn freeze_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), 100);
+		_(RawOrigin::Signed(caller), collection_id);
 
-		assert_eq!(Something::<T>::get().map(|v| v.block_number), Some(100u32.into()));
+		assert!(Collections::<T>::get(collection_id).unwrap().is_frozen);
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(CompositeStruct { block_number: 100u32.into() });
+	fn unfreeze_collection() {
 		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		Pallet::<T>::freeze_collection(RawOrigin::Signed(caller.clone()).into(), collection_id)
+			.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert!(!Collections::<T>::get(collection_id).unwrap().is_frozen);
+	}
+
+	#[benchmark]
+	fn set_collection_royalty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let recipient: T::AccountId = account("recipient", 1, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, T::MaxRoyalty::get(), recipient.clone());
+
+		assert_eq!(Collections::<T>::get(collection_id).unwrap().royalty_recipient, recipient);
+	}
+
+	#[benchmark]
+	fn set_collection_gating() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let rule =
+			GatingRule { cast_hash: CAST, actions: EngagementActions { like: true, recast: true } };
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, Some(rule));
+
+		assert!(CollectionGating::<T>::contains_key(collection_id));
+	}
+
+	/// One step removes at most `RemoveItemsLimit` items, so `n` covers a single step. Each
+	/// item has the most offers.
+	#[benchmark]
+	fn destroy_collection(n: Linear<0, { T::RemoveItemsLimit::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		for _ in 0..n {
+			let item_id = create_item::<T>(&caller, collection_id);
+			fill_offers::<T>(collection_id, item_id);
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, n);
+
+		assert!(!Collections::<T>::contains_key(collection_id));
+	}
+
+	#[benchmark]
+	fn create_nft(m: Linear<0, MAX_METADATA>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collection_id, metadata(m), price::<T>());
+
+		assert!(Nfts::<T>::contains_key(collection_id, 0));
+		assert!(OwnedNfts::<T>::contains_key(&caller, (collection_id, 0)));
+	}
+
+	#[benchmark]
+	fn batch_create_nfts(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let items = (0..n).map(|_| (metadata(MAX_METADATA), price::<T>())).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, items);
+
+		assert_eq!(NextItemId::<T>::get(collection_id), n);
+	}
+
+	#[benchmark]
+	fn transfer_nft() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		fund::<T>(&receiver);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id, receiver.clone());
+
+		assert_eq!(Nfts::<T>::get(collection_id, item_id).unwrap().owner, receiver);
+	}
+
+	/// The item has locked metadata and the most offers.
+	#[benchmark]
+	fn burn_nft() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+		Pallet::<T>::lock_nft_metadata(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection_id,
+			item_id,
+		)
+		.unwrap();
+		fill_offers::<T>(collection_id, item_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id);
+
+		assert!(!Nfts::<T>::contains_key(collection_id, item_id));
+		assert_eq!(OfferCount::<T>::get(collection_id, item_id), 0);
+	}
+
+	#[benchmark]
+	fn mint_nft() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let collection_id = setup_collection::<T>(&seller);
+		let item_id = create_item::<T>(&seller, collection_id);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let attestation = gate::<T>(collection_id, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collection_id, item_id, Some(attestation));
+
+		assert_eq!(Nfts::<T>::get(collection_id, item_id).unwrap().owner, caller);
+	}
+
+	#[benchmark]
+	fn list_nft() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+		Pallet::<T>::delist_nft(RawOrigin::Signed(caller.clone()).into(), collection_id, item_id)
+			.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id, price::<T>());
+
+		assert!(Listings::<T>::contains_key(collection_id, item_id));
+	}
+
+	#[benchmark]
+	fn update_price() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+		let new_price = price::<T>().saturating_mul(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id, new_price);
+
+		assert_eq!(Listings::<T>::get(collection_id, item_id), Some(new_price));
+	}
+
+	#[benchmark]
+	fn delist_nft() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id);
+
+		assert!(!Listings::<T>::contains_key(collection_id, item_id));
+	}
+
+	#[benchmark]
+	fn update_nft_metadata(m: Linear<0, MAX_METADATA>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id, vec![1u8; m as usize]);
+
+		assert_eq!(Nfts::<T>::get(collection_id, item_id).unwrap().metadata.len(), m as usize);
+	}
+
+	#[benchmark]
+	fn lock_nft_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id);
+
+		assert!(MetadataLocked::<T>::contains_key(collection_id, item_id));
+	}
+
+	#[benchmark]
+	fn withdraw_fees() -> Result<(), BenchmarkError> {
+		fund::<T>(&Pallet::<T>::account_id());
+		let dest: T::AccountId = account("dest", 0, 0);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, dest.clone(), price::<T>());
+
+		assert_eq!(T::Currency::balance(&dest), price::<T>());
+		Ok(())
+	}
+
+	/// The schedule of the end block is one entry short of full.
+	#[benchmark]
+	fn create_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+		Pallet::<T>::delist_nft(RawOrigin::Signed(caller.clone()).into(), collection_id, item_id)
+			.unwrap();
+		let duration = T::MinAuctionDuration::get();
+		let end = frame_system::Pallet::<T>::block_number() + duration;
+		let others = (1..T::MaxAuctionsPerBlock::get()).map(|i| (CollectionId::MAX, i));
+		AuctionsEndingAt::<T>::insert(end, BoundedVec::truncate_from(others.collect()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id, price::<T>(), duration);
+
+		assert!(Auctions::<T>::contains_key(collection_id, item_id));
+	}
+
+	/// Outbids a previous bidder late enough to extend the auction.
+	#[benchmark]
+	fn bid() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let (collection_id, item_id) = auction_item::<T>(&seller);
+		let previous: T::AccountId = account("bidder", 0, 0);
+		fund::<T>(&previous);
+		Pallet::<T>::bid(
+			RawOrigin::Signed(previous).into(),
+			collection_id,
+			item_id,
+			price::<T>(),
+			None,
+		)
+		.unwrap();
+		let end = Auctions::<T>::get(collection_id, item_id).unwrap().end;
+		frame_system::Pallet::<T>::set_block_number(end - 1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let attestation = gate::<T>(collection_id, &caller);
+		let amount = price::<T>().saturating_mul(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collection_id, item_id, amount, Some(attestation));
+
+		let auction = Auctions::<T>::get(collection_id, item_id).unwrap();
+		assert_eq!(auction.highest_bid, Some((caller, amount)));
+	}
+
+	#[benchmark]
+	fn cancel_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (collection_id, item_id) = auction_item::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id);
+
+		assert!(!Auctions::<T>::contains_key(collection_id, item_id));
+	}
+
+	/// Settles `n` auctions ending in the same block, each sold to its highest bidder.
+	#[benchmark]
+	fn settle_auctions(n: Linear<0, { T::MaxAuctionsPerBlock::get() }>) {
+		let mut end = frame_system::Pallet::<T>::block_number() + T::MinAuctionDuration::get();
+		let mut auctions = Vec::new();
+		for i in 0..n {
+			let seller: T::AccountId = account("seller", i, 0);
+			let (collection_id, item_id) = auction_item::<T>(&seller);
+			let bidder: T::AccountId = account("bidder", i, 0);
+			fund::<T>(&bidder);
+			Pallet::<T>::bid(
+				RawOrigin::Signed(bidder.clone()).into(),
+				collection_id,
+				item_id,
+				price::<T>(),
+				None,
+			)
+			.unwrap();
+			end = Auctions::<T>::get(collection_id, item_id).unwrap().end;
+			auctions.push((collection_id, item_id, bidder));
+		}
+		assert_eq!(AuctionsEndingAt::<T>::get(end).len() as u32, n);
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(end);
+		}
+
+		for (collection_id, item_id, bidder) in auctions {
+			assert_eq!(Nfts::<T>::get(collection_id, item_id).unwrap().owner, bidder);
+		}
+	}
+
+	/// Replaces a previous offer of the caller.
+	#[benchmark]
+	fn make_offer() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let collection_id = setup_collection::<T>(&seller);
+		let item_id = create_item::<T>(&seller, collection_id);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		Pallet::<T>::make_offer(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection_id,
+			item_id,
+			price::<T>(),
+			expiry,
+			None,
+		)
+		.unwrap();
+		let attestation = gate::<T>(collection_id, &caller);
+		let amount = price::<T>().saturating_mul(2u32.into());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			collection_id,
+			item_id,
+			amount,
+			expiry,
+			Some(attestation),
+		);
+
+		assert_eq!(Offers::<T>::get((collection_id, item_id, &caller)).unwrap().amount, amount);
+	}
+
+	#[benchmark]
+	fn withdraw_offer() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let collection_id = setup_collection::<T>(&seller);
+		let item_id = create_item::<T>(&seller, collection_id);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		Pallet::<T>::make_offer(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection_id,
+			item_id,
+			price::<T>(),
+			expiry,
+			None,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collection_id, item_id);
+
+		assert!(!Offers::<T>::contains_key((collection_id, item_id, &caller)));
+	}
+
+	#[benchmark]
+	fn accept_offer() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = setup_collection::<T>(&caller);
+		let item_id = create_item::<T>(&caller, collection_id);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		fund::<T>(&buyer);
+		let expiry = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		Pallet::<T>::make_offer(
+			RawOrigin::Signed(buyer.clone()).into(),
+			collection_id,
+			item_id,
+			price::<T>(),
+			expiry,
+			None,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id, buyer.clone());
+
+		assert_eq!(Nfts::<T>::get(collection_id, item_id).unwrap().owner, buyer);
+	}
+
+	#[benchmark]
+	fn clear_expired_offer() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let collection_id = setup_collection::<T>(&seller);
+		let item_id = create_item::<T>(&seller, collection_id);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		fund::<T>(&buyer);
+		let now = frame_system::Pallet::<T>::block_number();
+		Pallet::<T>::make_offer(
+			RawOrigin::Signed(buyer.clone()).into(),
+			collection_id,
+			item_id,
+			price::<T>(),
+			now,
+			None,
+		)
+		.unwrap();
+		frame_system::Pallet::<T>::set_block_number(now + 1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, item_id, buyer.clone());
+
+		assert!(!Offers::<T>::contains_key((collection_id, item_id, &buyer)));
+	}
+
+	/// Replaces a profile that already claims the FID.
+	#[benchmark]
+	fn set_profile() {
+		let caller: T::AccountId = whitelisted_caller();
+		verified_artist::<T>(&caller);
+		link_with_profile::<T>(&caller, FID);
+		let links = vec![vec![1u8; MAX_LINK_LENGTH as usize]; MAX_LINKS as usize];
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			vec![1u8; MAX_NAME_LENGTH as usize],
+			vec![1u8; MAX_CID_LENGTH as usize],
+			links,
+			Some(FID),
+		);
+
+		assert_eq!(FidToArtist::<T>::get(FID), Some(caller));
+	}
+
+	#[benchmark]
+	fn clear_profile() {
+		let caller: T::AccountId = whitelisted_caller();
+		verified_artist::<T>(&caller);
+		link_with_profile::<T>(&caller, FID);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!ArtistProfiles::<T>::contains_key(&caller));
+		assert!(!FidToArtist::<T>::contains_key(FID));
+	}
+
+	/// Replaces both a previous link of the caller and a previous link of the FID, each claimed
+	/// by a profile.
+	#[benchmark]
+	fn link_fid() {
+		let caller: T::AccountId = whitelisted_caller();
+		let other: T::AccountId = account("other", 0, 0);
+		link_with_profile::<T>(&caller, FID + 1);
+		link_with_profile::<T>(&other, FID);
+		let signer = sp_io::crypto::ed25519_generate(KEY_TYPE, None);
+		T::BenchmarkHelper::add_fid_signer(FID, signer);
+		let payload = Pallet::<T>::fid_link_payload(FID, &caller, FidLinkNonce::<T>::get(&caller));
+		let signature = sp_io::crypto::ed25519_sign(KEY_TYPE, &signer, &payload).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), FID, signer, signature);
+
+		assert_eq!(FidOf::<T>::get(&caller), Some(FID));
+		assert!(!FidOf::<T>::contains_key(&other));
+	}
+
+	#[benchmark]
+	fn unlink_fid() {
+		let caller: T::AccountId = whitelisted_caller();
+		link_with_profile::<T>(&caller, FID);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!FidOf::<T>::contains_key(&caller));
+		assert!(!FidToArtist::<T>::contains_key(FID));
+	}
+
+	#[benchmark]
+	fn request_engagement_check() {
+		let caller: T::AccountId = whitelisted_caller();
+		FidOf::<T>::insert(&caller, FID);
+		AccountOfFid::<T>::insert(FID, &caller);
+		PendingEngagementCheckCount::<T>::insert(&caller, T::MaxPendingEngagementChecks::get() - 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), FID, FID, CAST);
+
+		assert!(PendingEngagementChecks::<T>::contains_key(FID, CAST));
+	}
+
+	#[benchmark]
+	fn submit_reaction() {
+		T::BenchmarkHelper::add_fid_signer(FID, ed25519::Public::from_raw(REACTION_SIGNER));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), REACTION.to_vec());
+
+		let cast = hex_literal::hex!("a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9");
+		assert!(VerifiedEngagements::<T>::get(FID, cast).is_some_and(|actions| actions.like));
+	}
+
+	#[benchmark]
+	fn submit_engagement_verdict() {
+		let now = frame_system::Pallet::<T>::block_number();
+		pending_check::<T>(now);
+		let attester = sp_io::crypto::ed25519_generate(KEY_TYPE, None);
+		T::BenchmarkHelper::trust_attester(attester);
+		let verdict = EngagementVerdict {
+			fid: FID,
+			cast_hash: CAST,
+			requested_at: now,
+			actions: EngagementActions { like: true, recast: true },
+			attester,
+		};
+		let payload = Pallet::<T>::engagement_verdict_payload(&verdict);
+		let signature = sp_io::crypto::ed25519_sign(KEY_TYPE, &attester, &payload).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::None, verdict, signature);
+
+		assert!(!PendingEngagementChecks::<T>::contains_key(FID, CAST));
+		assert!(VerifiedEngagements::<T>::contains_key(FID, CAST));
+	}
+
+	#[benchmark]
+	fn clear_expired_engagement_check() {
+		let now = frame_system::Pallet::<T>::block_number();
+		pending_check::<T>(now);
+		frame_system::Pallet::<T>::set_block_number(
+			now + T::EngagementCheckTimeout::get() + 1u32.into(),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		_(RawOrigin::Signed(caller), FID, CAST);
 
-		assert_eq!(Something::<T>::get().map(|v| v.block_number), Some(101u32.into()));
+		assert!(!PendingEngagementChecks::<T>::contains_key(FID, CAST));
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Template, crate::mock::new_bench_ext(), crate::mock::Test);
 }
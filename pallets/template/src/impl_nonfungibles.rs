@@ -0,0 +1,212 @@
+//! Implementations of the `nonfungibles_v2` traits, so other pallets and XCM adapters can treat
+//! marketplace collections as standard NFTs.
+//!
+//! The traits go through the same checks as the extrinsics: collections are only created for
+//! verified artists, frozen collections accept no new items, and items in an auction cannot be
+//! transferred or burned. Calls that change storage run in their own storage layer, so a failing
+//! call leaves no partial changes behind. Attributes are read from the JSON metadata of items and
+//! collections and cannot be set separately.
+
+use crate::{
+    CollectionConfig, CollectionId, Collections, Config, Error, Event, ItemId, NextCollectionId,
+    Nfts, OwnedNfts, Pallet,
+};
+use alloc::vec::Vec;
+use frame_support::{
+    dispatch::DispatchResult,
+    storage::{with_storage_layer, KeyPrefixIterator},
+    traits::tokens::nonfungibles_v2::{
+        Create, Destroy, Inspect, InspectEnumerable, Mutate, Transfer,
+    },
+};
+use sp_runtime::{traits::Zero, DispatchError};
+
+/// The value under `key` of a JSON object in `metadata`: strings as their UTF-8 bytes, other
+/// values as JSON.
+fn metadata_attribute(metadata: &[u8], key: &[u8]) -> Option<Vec<u8>> {
+    let key = core::str::from_utf8(key).ok()?;
+    let serde_json::Value::Object(fields) = serde_json::from_slice(metadata).ok()? else {
+        return None;
+    };
+    match fields.get(key)? {
+        serde_json::Value::String(value) => Some(value.clone().into_bytes()),
+        value => serde_json::to_vec(value).ok(),
+    }
+}
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type ItemId = ItemId;
+    type CollectionId = CollectionId;
+
+    fn owner(collection: &CollectionId, item: &ItemId) -> Option<T::AccountId> {
+        Nfts::<T>::get(collection, item).map(|nft| nft.owner)
+    }
+
+    fn collection_owner(collection: &CollectionId) -> Option<T::AccountId> {
+        Collections::<T>::get(collection).map(|collection| collection.creator)
+    }
+
+    fn attribute(collection: &CollectionId, item: &ItemId, key: &[u8]) -> Option<Vec<u8>> {
+        let nft = Nfts::<T>::get(collection, item)?;
+        metadata_attribute(&nft.metadata, key)
+    }
+
+    fn collection_attribute(collection: &CollectionId, key: &[u8]) -> Option<Vec<u8>> {
+        let collection = Collections::<T>::get(collection)?;
+        metadata_attribute(&collection.metadata, key)
+    }
+
+    fn can_transfer(collection: &CollectionId, item: &ItemId) -> bool {
+        Nfts::<T>::contains_key(collection, item)
+            && Self::ensure_not_in_auction(*collection, *item).is_ok()
+    }
+}
+
+impl<T: Config> InspectEnumerable<T::AccountId> for Pallet<T> {
+    type CollectionsIterator = KeyPrefixIterator<CollectionId>;
+    type ItemsIterator = KeyPrefixIterator<ItemId>;
+    type OwnedIterator = KeyPrefixIterator<(CollectionId, ItemId)>;
+    type OwnedInCollectionIterator = alloc::vec::IntoIter<ItemId>;
+
+    fn collections() -> Self::CollectionsIterator {
+        Collections::<T>::iter_keys()
+    }
+
+    fn items(collection: &CollectionId) -> Self::ItemsIterator {
+        Nfts::<T>::iter_key_prefix(collection)
+    }
+
+    fn owned(who: &T::AccountId) -> Self::OwnedIterator {
+        OwnedNfts::<T>::iter_key_prefix(who)
+    }
+
+    fn owned_in_collection(
+        collection: &CollectionId,
+        who: &T::AccountId,
+    ) -> Self::OwnedInCollectionIterator {
+        OwnedNfts::<T>::iter_key_prefix(who)
+            .filter(|(collection_id, _)| collection_id == collection)
+            .map(|(_, item_id)| item_id)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// `who` becomes the creator of the collection, with empty metadata. The pallet has no
+/// separate collection admin, so `admin` is not used.
+impl<T: Config> Create<T::AccountId, CollectionConfig<T::AccountId>> for Pallet<T> {
+    fn create_collection(
+        who: &T::AccountId,
+        admin: &T::AccountId,
+        config: &CollectionConfig<T::AccountId>,
+    ) -> Result<CollectionId, DispatchError> {
+        let collection_id = NextCollectionId::<T>::get();
+        Self::create_collection_with_id(collection_id, who, admin, config)?;
+        Ok(collection_id)
+    }
+
+    fn create_collection_with_id(
+        collection: CollectionId,
+        who: &T::AccountId,
+        _admin: &T::AccountId,
+        config: &CollectionConfig<T::AccountId>,
+    ) -> Result<(), DispatchError> {
+        with_storage_layer(|| {
+            Self::do_create_collection(
+                collection,
+                who,
+                Vec::new(),
+                config.royalty,
+                config.royalty_recipient.clone(),
+                config.max_supply,
+            )
+        })
+    }
+}
+
+impl<T: Config> Destroy<T::AccountId> for Pallet<T> {
+    /// The number of items in the collection.
+    type DestroyWitness = u32;
+
+    fn get_destroy_witness(collection: &CollectionId) -> Option<u32> {
+        Collections::<T>::get(collection).map(|collection| collection.items)
+    }
+
+    fn destroy(
+        collection: CollectionId,
+        witness: u32,
+        maybe_check_owner: Option<T::AccountId>,
+    ) -> Result<u32, DispatchError> {
+        with_storage_layer(|| {
+            Self::do_destroy_collection(collection, witness, maybe_check_owner.as_ref())
+        })
+    }
+}
+
+/// Minted items are unlisted, with empty metadata and no price. Their deposit is held from the
+/// new owner, since item deposits follow the item, so `deposit_collection_owner` is not used.
+///
+/// `burn` follows `burn_nft` and refuses items sold at least once with `NFTAlreadySold`, with
+/// or without `maybe_check_owner`, so nothing burns an item a collector paid for.
+impl<T: Config> Mutate<T::AccountId, ()> for Pallet<T> {
+    fn mint_into(
+        collection: &CollectionId,
+        item: &ItemId,
+        who: &T::AccountId,
+        _config: &(),
+        _deposit_collection_owner: bool,
+    ) -> DispatchResult {
+        with_storage_layer(|| {
+            Self::ensure_collection_not_frozen(*collection)?;
+            Self::do_mint(who, *collection, *item, Vec::new(), Zero::zero())?;
+            Self::deposit_event(Event::NFTMinted(*collection, *item, who.clone()));
+            Ok(())
+        })
+    }
+
+    fn burn(
+        collection: &CollectionId,
+        item: &ItemId,
+        maybe_check_owner: Option<&T::AccountId>,
+    ) -> DispatchResult {
+        with_storage_layer(|| Self::do_burn(*collection, *item, maybe_check_owner))
+    }
+
+    fn set_item_metadata(
+        who: Option<&T::AccountId>,
+        collection: &CollectionId,
+        item: &ItemId,
+        data: &[u8],
+    ) -> DispatchResult {
+        with_storage_layer(|| Self::do_set_item_metadata(who, *collection, *item, data.to_vec()))
+    }
+
+    fn set_collection_metadata(
+        who: Option<&T::AccountId>,
+        collection: &CollectionId,
+        data: &[u8],
+    ) -> DispatchResult {
+        with_storage_layer(|| Self::do_set_collection_metadata(who, *collection, data.to_vec()))
+    }
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+    fn transfer(
+        collection: &CollectionId,
+        item: &ItemId,
+        destination: &T::AccountId,
+    ) -> DispatchResult {
+        with_storage_layer(|| {
+            let owner = Self::owner(collection, item).ok_or(Error::<T>::NFTNotFound)?;
+            Self::ensure_not_in_auction(*collection, *item)?;
+            Self::transfer_ownership(*collection, *item, &owner, destination, None)?;
+            Self::deposit_event(Event::NFTTransferred(
+                *collection,
+                *item,
+                owner,
+                destination.clone(),
+            ));
+            Ok(())
+        })
+    }
+}
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -10,15 +12,39 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod impl_nonfungibles;
+pub mod migrations;
+pub mod offchain;
 pub mod weights;
 pub use weights::*;
+
+const LOG_TARGET: &str = "runtime::nft-marketplace";
+
+// The `TryState` impl that `frame_support` generates for the hooks logs failures with
+// `map_err`, which trips `manual_inspect` under `try-runtime`.
+#[cfg_attr(feature = "try-runtime", allow(clippy::manual_inspect))]
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use farcaster_primitives::{
+        FarcasterNetwork, MessageBody, MessageError, ReactionBody, ReactionTarget, ReactionType,
+    };
     use frame_support::pallet_prelude::*;
-    use frame_support::sp_runtime::traits::AccountIdConversion;
-    use frame_support::traits::Currency;
-    use frame_system::pallet_prelude::*;
+    use frame_support::sp_runtime::{
+        traits::{AccountIdConversion, Saturating, Zero},
+        Perbill,
+    };
+    use frame_support::traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::{DepositConsequence, Precision, Preservation, Provenance},
+        Contains,
+    };
+    use frame_system::{
+        offchain::{SendTransactionTypes, SubmitTransaction},
+        pallet_prelude::*,
+    };
+    use sp_core::{crypto::KeyTypeId, ed25519};
+    use sp_runtime::offchain::storage::StorageValueRef;
     use sp_std::vec::Vec;
 
     #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
@@ -33,26 +59,308 @@ pub mod pallet {
     pub struct Collection<AccountId> {
         pub creator: AccountId,
         pub metadata: BoundedVec<u8, ConstU32<256>>,
-        pub nfts: BoundedVec<ItemId, ConstU32<256>>, // List of NFT IDs in the collection
+        /// Number of items currently in the collection.
+        pub items: u32,
+        /// Most items the collection may hold at once, if the creator set a cap.
+        pub max_supply: Option<u32>,
+        /// Share of every sale price paid to `royalty_recipient`.
+        pub royalty: Perbill,
+        pub royalty_recipient: AccountId,
+        /// A frozen collection accepts no new items, metadata edits or burns.
+        pub is_frozen: bool,
+    }
+
+    /// Settings of a collection created through
+    /// [`nonfungibles_v2::Create`](frame_support::traits::tokens::nonfungibles_v2::Create).
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
+    pub struct CollectionConfig<AccountId> {
+        pub royalty: Perbill,
+        /// Receives the royalties; the creator when not set.
+        pub royalty_recipient: Option<AccountId>,
+        pub max_supply: Option<u32>,
+    }
+
+    /// Onboarding state of an artist.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub enum ArtistStatus {
+        /// Registered and waiting for approval.
+        Pending,
+        /// Approved; can create collections.
+        Verified,
+        /// Approval revoked by the artist admin.
+        Suspended,
+    }
+
+    /// A Farcaster account id.
+    pub type Fid = u64;
+
+    /// Public profile of an artist, as shown by the frontend and frame renderers.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct ArtistProfile {
+        pub display_name: BoundedVec<u8, ConstU32<MAX_NAME_LENGTH>>,
+        /// IPFS CID of the avatar image.
+        pub avatar_cid: BoundedVec<u8, ConstU32<MAX_CID_LENGTH>>,
+        pub links: BoundedVec<BoundedVec<u8, ConstU32<MAX_LINK_LENGTH>>, ConstU32<MAX_LINKS>>,
+        /// Farcaster account the artist claims.
+        pub fid: Option<Fid>,
+    }
+
+    /// Hash of a Farcaster cast.
+    pub type CastHash = [u8; 20];
+
+    /// Reactions to a cast.
+    #[derive(
+        Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct EngagementActions {
+        pub like: bool,
+        pub recast: bool,
+    }
+
+    impl EngagementActions {
+        /// Whether every reaction in `required` is also in `self`.
+        pub fn covers(&self, required: &EngagementActions) -> bool {
+            (self.like || !required.like) && (self.recast || !required.recast)
+        }
+
+        /// Reactions in either `self` or `other`.
+        pub fn merge(self, other: EngagementActions) -> EngagementActions {
+            EngagementActions {
+                like: self.like || other.like,
+                recast: self.recast || other.recast,
+            }
+        }
+    }
+
+    /// Facts about Farcaster accounts finalised off this pallet, e.g. by an oracle committee.
+    pub trait SocialAttestations {
+        /// Reactions of `fid` to the cast `cast_hash`.
+        fn engagement(fid: Fid, cast_hash: &CastHash) -> EngagementActions;
+        /// Whether `fid` follows `target`.
+        fn follows(fid: Fid, target: Fid) -> bool;
+        /// Number of followers of `fid`, if attested.
+        fn follower_count(fid: Fid) -> Option<u64>;
+    }
+
+    impl SocialAttestations for () {
+        fn engagement(_: Fid, _: &CastHash) -> EngagementActions {
+            EngagementActions::default()
+        }
+
+        fn follows(_: Fid, _: Fid) -> bool {
+            false
+        }
+
+        fn follower_count(_: Fid) -> Option<u64> {
+            None
+        }
+    }
+
+    /// Runtime hooks the benchmarks need to reach the most expensive path of each call.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper {
+        /// Make `attester` one of the [`Config::TrustedAttesters`].
+        fn trust_attester(attester: ed25519::Public);
+        /// Make `signer` one of the [`Config::FidSigners`] of `fid`.
+        fn add_fid_signer(fid: Fid, signer: ed25519::Public);
+    }
+
+    /// Engagement with a cast that buyers of a gated collection must prove.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct GatingRule {
+        pub cast_hash: CastHash,
+        /// Reactions the buyer's FID must have made on the cast.
+        pub actions: EngagementActions,
+    }
+
+    /// Statement by a trusted attester that the caller's FID made the reactions required by a
+    /// collection's gating rule.
+    ///
+    /// `signature` is made by `attester` over [`Pallet::engagement_payload`].
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    pub struct EngagementAttestation<BlockNumber> {
+        pub attester: ed25519::Public,
+        /// Last block at which the attestation can be used.
+        pub expiry: BlockNumber,
+        pub signature: ed25519::Signature,
+    }
+
+    /// Outcome of an engagement check, signed by the offchain worker of a trusted attester.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+    pub struct EngagementVerdict<BlockNumber> {
+        pub fid: Fid,
+        pub cast_hash: CastHash,
+        /// Block at which the check was requested.
+        pub requested_at: BlockNumber,
+        /// Reactions of `fid` to the cast found on the Hub.
+        pub actions: EngagementActions,
+        pub attester: ed25519::Public,
+    }
+
+    /// Engagement check waiting for the offchain worker.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct PendingEngagementCheck<AccountId, BlockNumber> {
+        pub requester: AccountId,
+        /// FID that posted the cast, which the Hub looks reactions up by.
+        pub cast_fid: Fid,
+        pub requested_at: BlockNumber,
     }
 
     pub type CollectionId = u32;
     pub type ItemId = u32;
 
+    /// A timed English auction for a single NFT.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct Auction<AccountId, Balance, BlockNumber> {
+        pub seller: AccountId,
+        /// Lowest acceptable bid.
+        pub reserve: Balance,
+        /// Block at which the auction is settled. Bids are accepted strictly before it.
+        pub end: BlockNumber,
+        /// Current highest bidder and the amount held from them.
+        pub highest_bid: Option<(AccountId, Balance)>,
+    }
+
+    /// An escrowed offer to buy an NFT, whether or not it is listed.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct Offer<Balance, BlockNumber> {
+        /// Amount held from the buyer.
+        pub amount: Balance,
+        /// Last block at which the offer can be accepted.
+        pub expiry: BlockNumber,
+    }
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NftOf<T> = NFT<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+    pub type AuctionOf<T> =
+        Auction<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+    pub type OfferOf<T> = Offer<BalanceOf<T>, BlockNumberFor<T>>;
+    pub type EngagementAttestationOf<T> = EngagementAttestation<BlockNumberFor<T>>;
+    pub type EngagementVerdictOf<T> = EngagementVerdict<BlockNumberFor<T>>;
+    pub type PendingEngagementCheckOf<T> =
+        PendingEngagementCheck<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type WeightInfo: WeightInfo;
-        type Currency: Currency<Self::AccountId>;
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The overarching hold reason, which must include this pallet's `HoldReason`.
+        type RuntimeHoldReason: From<HoldReason>;
         type PalletId: Get<frame_support::PalletId>;
+        /// Highest royalty a collection creator may set.
+        #[pallet::constant]
+        type MaxRoyalty: Get<Perbill>;
+        /// Share of every sale price kept by the marketplace in the pallet account.
+        #[pallet::constant]
+        type MarketplaceFee: Get<Perbill>;
+        /// Origin allowed to withdraw collected fees from the pallet account.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Shortest auction that can be created.
+        #[pallet::constant]
+        type MinAuctionDuration: Get<BlockNumberFor<Self>>;
+        /// Longest auction that can be created.
+        #[pallet::constant]
+        type MaxAuctionDuration: Get<BlockNumberFor<Self>>;
+        /// A bid placed less than this many blocks before the end pushes the end back to this
+        /// many blocks after the bid. Zero disables the extension.
+        #[pallet::constant]
+        type AntiSnipingPeriod: Get<BlockNumberFor<Self>>;
+        /// Maximum number of auctions that can be settled in a single block.
+        #[pallet::constant]
+        type MaxAuctionsPerBlock: Get<u32>;
+        /// Maximum number of open offers on a single item.
+        #[pallet::constant]
+        type MaxOffersPerItem: Get<u32>;
+        /// Smallest amount an offer can hold.
+        #[pallet::constant]
+        type MinOfferAmount: Get<BalanceOf<Self>>;
+        /// Longest an offer can stay open, in blocks from when it is made.
+        #[pallet::constant]
+        type MaxOfferDuration: Get<BlockNumberFor<Self>>;
+        /// Maximum number of items created by a single `batch_create_nfts` call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// Maximum number of items removed by a single `delete_collection` call.
+        #[pallet::constant]
+        type RemoveItemsLimit: Get<u32>;
+        /// Non-refundable fee paid into the pallet account on artist registration.
+        #[pallet::constant]
+        type ArtistRegistrationFee: Get<BalanceOf<Self>>;
+        /// Bond held from an artist while registered, refunded on deregistration.
+        #[pallet::constant]
+        type ArtistBond: Get<BalanceOf<Self>>;
+        /// Origin allowed to approve and revoke artists.
+        type ArtistAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Signer keys of each FID, e.g. a mirror of the Farcaster KeyRegistry. The pallet keeps
+        /// no signer keys of its own.
+        type FidSigners: Contains<(Fid, ed25519::Public)>;
+        /// Base deposit held from the creator of a collection.
+        #[pallet::constant]
+        type CollectionDeposit: Get<BalanceOf<Self>>;
+        /// Base deposit held from the owner of an item.
+        #[pallet::constant]
+        type ItemDeposit: Get<BalanceOf<Self>>;
+        /// Additional deposit per byte of collection or item metadata.
+        #[pallet::constant]
+        type DepositPerByte: Get<BalanceOf<Self>>;
+        /// Keys whose engagement attestations are accepted for gated collections.
+        type TrustedAttesters: Contains<ed25519::Public>;
+        /// Most pending engagement checks the offchain worker sends to the Hub in one block.
+        #[pallet::constant]
+        type MaxEngagementChecksPerBlock: Get<u32>;
+        /// Most engagement checks an account can have pending at once.
+        #[pallet::constant]
+        type MaxPendingEngagementChecks: Get<u32>;
+        /// Blocks after which a pending engagement check is no longer sent to the Hub and can be
+        /// cleared by anyone.
+        #[pallet::constant]
+        type EngagementCheckTimeout: Get<BlockNumberFor<Self>>;
+        /// Priority of the unsigned transactions carrying engagement verdicts.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+        /// Attested reactions that satisfy the gating of a collection without a signed
+        /// attestation.
+        type SocialAttestations: SocialAttestations;
+        /// Prepares the runtime for the benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
+    }
+
+    /// Reasons for the pallet to hold funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds of the current highest bidder of an auction.
+        AuctionBid,
+        /// Funds escrowed by an open offer.
+        Offer,
+        /// Bond of a registered artist.
+        ArtistBond,
+        /// Storage deposit of a collection, held from its creator.
+        CollectionDeposit,
+        /// Storage deposit of an item, held from its current owner.
+        ItemDeposit,
     }
 
     #[pallet::storage]
     #[pallet::getter(fn artists)]
-    pub type Artists<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+    pub type Artists<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ArtistStatus, OptionQuery>;
+
+    /// Number of live collections created by each artist.
+    #[pallet::storage]
+    #[pallet::getter(fn artist_collection_count)]
+    pub type ArtistCollectionCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn collections)]
@@ -67,7 +375,7 @@ pub mod pallet {
         CollectionId,
         Blake2_128Concat,
         ItemId,
-        NFT<T::AccountId, <T::Currency as Currency<T::AccountId>>::Balance>,
+        NftOf<T>,
         OptionQuery,
     >;
 
@@ -76,22 +384,273 @@ pub mod pallet {
     pub type NextItemId<T: Config> =
         StorageMap<_, Blake2_128Concat, CollectionId, ItemId, ValueQuery>;
 
+    /// Number of items of each collection owned by someone other than its creator.
+    #[pallet::storage]
+    #[pallet::getter(fn foreign_items)]
+    pub type ForeignItems<T: Config> =
+        StorageMap<_, Blake2_128Concat, CollectionId, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn next_collection_id)]
     pub type NextCollectionId<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
 
+    /// Active fixed-price listings, keyed like `Nfts`. An item can only be bought through
+    /// `mint_nft` while it has an entry here.
+    #[pallet::storage]
+    #[pallet::getter(fn listings)]
+    pub type Listings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CollectionId,
+        Blake2_128Concat,
+        ItemId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Reverse index of `Nfts` by owner, so an account's items can be listed without scanning
+    /// every collection.
+    #[pallet::storage]
+    #[pallet::getter(fn owned_nfts)]
+    pub type OwnedNfts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (CollectionId, ItemId),
+        (),
+        OptionQuery,
+    >;
+
+    /// Running auctions, keyed like `Nfts`.
+    #[pallet::storage]
+    #[pallet::getter(fn auctions)]
+    pub type Auctions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CollectionId,
+        Blake2_128Concat,
+        ItemId,
+        AuctionOf<T>,
+        OptionQuery,
+    >;
+
+    /// Deposit held from the creator of each collection.
+    #[pallet::storage]
+    #[pallet::getter(fn collection_deposit)]
+    pub type CollectionDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, CollectionId, BalanceOf<T>, ValueQuery>;
+
+    /// Deposit held from the owner of each item.
+    #[pallet::storage]
+    #[pallet::getter(fn item_deposit)]
+    pub type ItemDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CollectionId,
+        Blake2_128Concat,
+        ItemId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn artist_profile)]
+    pub type ArtistProfiles<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ArtistProfile, OptionQuery>;
+
+    /// Reverse index from a Farcaster FID to the artist whose profile claims it.
+    #[pallet::storage]
+    #[pallet::getter(fn fid_to_artist)]
+    pub type FidToArtist<T: Config> = StorageMap<_, Twox64Concat, Fid, T::AccountId, OptionQuery>;
+
+    /// FID linked to each account through `link_fid`.
+    #[pallet::storage]
+    #[pallet::getter(fn fid_of)]
+    pub type FidOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Fid, OptionQuery>;
+
+    /// Account linked to each FID through `link_fid`.
+    #[pallet::storage]
+    #[pallet::getter(fn account_of_fid)]
+    pub type AccountOfFid<T: Config> = StorageMap<_, Twox64Concat, Fid, T::AccountId, OptionQuery>;
+
+    /// Nonce the next `link_fid` signature for an account must cover.
+    #[pallet::storage]
+    #[pallet::getter(fn fid_link_nonce)]
+    pub type FidLinkNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Engagement required to buy items of a collection.
+    #[pallet::storage]
+    #[pallet::getter(fn collection_gating)]
+    pub type CollectionGating<T: Config> =
+        StorageMap<_, Blake2_128Concat, CollectionId, GatingRule, OptionQuery>;
+
+    /// Nonce the next engagement attestation for an account must cover.
+    #[pallet::storage]
+    #[pallet::getter(fn engagement_nonce)]
+    pub type EngagementNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Engagement checks waiting for the offchain worker.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_engagement_checks)]
+    pub type PendingEngagementChecks<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        Fid,
+        Blake2_128Concat,
+        CastHash,
+        PendingEngagementCheckOf<T>,
+        OptionQuery,
+    >;
+
+    /// Number of engagement checks each account has pending.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_engagement_check_count)]
+    pub type PendingEngagementCheckCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Reactions of an FID to a cast, reported by the offchain worker or proven with signed
+    /// Farcaster messages.
+    #[pallet::storage]
+    #[pallet::getter(fn verified_engagements)]
+    pub type VerifiedEngagements<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        Fid,
+        Blake2_128Concat,
+        CastHash,
+        EngagementActions,
+        OptionQuery,
+    >;
+
+    /// Items whose metadata can no longer be changed.
+    #[pallet::storage]
+    #[pallet::getter(fn metadata_locked)]
+    pub type MetadataLocked<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CollectionId,
+        Blake2_128Concat,
+        ItemId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Auctions to settle at the start of each block.
+    #[pallet::storage]
+    #[pallet::getter(fn auctions_ending_at)]
+    pub type AuctionsEndingAt<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(CollectionId, ItemId), T::MaxAuctionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Open offers, keyed by (collection, item, buyer).
+    #[pallet::storage]
+    #[pallet::getter(fn offers)]
+    pub type Offers<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, CollectionId>,
+            NMapKey<Blake2_128Concat, ItemId>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+        ),
+        OfferOf<T>,
+        OptionQuery,
+    >;
+
+    /// Number of open offers on each item, capped at `MaxOffersPerItem`.
+    #[pallet::storage]
+    #[pallet::getter(fn offer_count)]
+    pub type OfferCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CollectionId,
+        Blake2_128Concat,
+        ItemId,
+        u32,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         ArtistRegistered(T::AccountId),
+        /// The artist left and got their bond back.
+        ArtistDeregistered(T::AccountId),
+        ArtistApproved(T::AccountId),
+        ArtistRevoked(T::AccountId),
+        ArtistProfileSet(T::AccountId),
+        ArtistProfileCleared(T::AccountId),
+        FidLinked(Fid, T::AccountId),
+        FidUnlinked(Fid, T::AccountId),
         CollectionCreated(CollectionId, T::AccountId),
         CollectionUpdated(CollectionId),
         CollectionFrozen(CollectionId),
+        CollectionUnfrozen(CollectionId),
         CollectionDeleted(CollectionId),
+        /// Part of a collection's items were removed; call `delete_collection` again to finish:
+        /// (collection, items removed, items left).
+        CollectionItemsRemoved(CollectionId, u32, u32),
         NFTMinted(CollectionId, ItemId, T::AccountId),
         NFTBatchMinted(CollectionId, Vec<ItemId>, T::AccountId),
         NFTTransferred(CollectionId, ItemId, T::AccountId, T::AccountId),
         NFTBurned(CollectionId, ItemId, T::AccountId),
+        NFTListed(CollectionId, ItemId, BalanceOf<T>),
+        NFTPriceUpdated(CollectionId, ItemId, BalanceOf<T>),
+        NFTDelisted(CollectionId, ItemId),
+        NFTMetadataUpdated(CollectionId, ItemId),
+        /// The item's metadata can no longer be changed.
+        NFTMetadataLocked(CollectionId, ItemId),
+        /// The gating rule of a collection was set or removed.
+        CollectionGatingSet(CollectionId, Option<GatingRule>),
+        EngagementCheckRequested(Fid, CastHash),
+        /// Reactions of an FID to a cast were verified.
+        EngagementVerified(Fid, CastHash, EngagementActions),
+        /// Royalty settings of a collection changed: (collection, royalty, recipient).
+        CollectionRoyaltySet(CollectionId, Perbill, T::AccountId),
+        /// Royalty paid out on a sale: (collection, item, recipient, amount).
+        RoyaltyPaid(CollectionId, ItemId, T::AccountId, BalanceOf<T>),
+        /// Marketplace fee collected on a sale: (collection, item, amount).
+        MarketplaceFeePaid(CollectionId, ItemId, BalanceOf<T>),
+        /// Collected fees withdrawn from the pallet account: (destination, amount).
+        FeesWithdrawn(T::AccountId, BalanceOf<T>),
+        /// (collection, item, reserve, end block)
+        AuctionCreated(CollectionId, ItemId, BalanceOf<T>, BlockNumberFor<T>),
+        /// (collection, item, bidder, amount)
+        BidPlaced(CollectionId, ItemId, T::AccountId, BalanceOf<T>),
+        /// A late bid pushed the end of an auction back: (collection, item, new end block).
+        AuctionExtended(CollectionId, ItemId, BlockNumberFor<T>),
+        /// (collection, item, winner, amount)
+        AuctionSettled(CollectionId, ItemId, T::AccountId, BalanceOf<T>),
+        /// The auction ended without bids, or was cancelled by the seller.
+        AuctionCancelled(CollectionId, ItemId),
+        /// (collection, item, buyer, amount, expiry block)
+        OfferMade(
+            CollectionId,
+            ItemId,
+            T::AccountId,
+            BalanceOf<T>,
+            BlockNumberFor<T>,
+        ),
+        /// (collection, item, buyer)
+        OfferWithdrawn(CollectionId, ItemId, T::AccountId),
+        /// (collection, item, seller, buyer, amount)
+        OfferAccepted(
+            CollectionId,
+            ItemId,
+            T::AccountId,
+            T::AccountId,
+            BalanceOf<T>,
+        ),
+        /// An expired offer was cleared and its funds released: (collection, item, buyer).
+        OfferExpired(CollectionId, ItemId, T::AccountId),
+        /// A pending engagement check timed out unanswered and was cleared.
+        EngagementCheckExpired(Fid, CastHash),
     }
 
     #[pallet::error]
@@ -106,13 +665,88 @@ pub mod pallet {
         NFTAlreadySold,
         MetadataInvalid,
         PriceNotSet,
+        NotListed,
+        AlreadyListed,
+        RoyaltyTooHigh,
+        NftInAuction,
+        AuctionNotFound,
+        AuctionEnded,
+        InvalidAuctionDuration,
+        /// Too many auctions already end in the same block.
+        AuctionScheduleFull,
+        BidBelowReserve,
+        BidTooLow,
+        /// The seller cannot bid on their own auction.
+        SellerCannotBid,
+        AuctionHasBids,
+        OfferNotFound,
+        OfferExpired,
+        OfferNotExpired,
+        /// Owners cannot make offers on their own NFTs.
+        CannotOfferOnOwnNft,
+        /// The item already has `MaxOffersPerItem` open offers.
+        TooManyOffers,
+        EmptyBatch,
+        /// More items than `MaxBatchSize` in a single batch.
+        BatchTooLarge,
+        /// The item's metadata has been locked.
+        MetadataLocked,
+        /// The collection is not frozen.
+        CollectionNotFrozen,
+        /// Someone other than the creator still owns items of the collection.
+        ItemsOwnedByOthers,
+        /// The witness does not cover the number of items in the collection.
+        BadWitness,
+        /// The artist has not been approved yet, or was suspended.
+        ArtistNotVerified,
+        ArtistAlreadyVerified,
+        ArtistAlreadySuspended,
+        /// The artist still has collections.
+        ArtistHasCollections,
+        /// A profile field is longer than allowed.
+        ProfileFieldTooLong,
+        TooManyLinks,
+        ProfileNotFound,
+        /// The key is not a registered signer of the FID.
+        UnknownFidSigner,
+        /// The signature does not cover the expected `link_fid` payload.
+        BadFidSignature,
+        /// The account has not linked this FID.
+        FidNotLinked,
+        /// The collection is gated and no engagement attestation was given.
+        EngagementRequired,
+        UntrustedAttester,
+        AttestationExpired,
+        /// The signature does not cover the expected engagement payload.
+        BadAttestation,
+        EngagementCheckPending,
+        /// The Farcaster message is malformed, or its hash or signature is wrong.
+        InvalidFarcasterMessage,
+        /// The Farcaster message is not a like or recast of a cast.
+        NotAReaction,
+        /// The Farcaster message is not from mainnet.
+        WrongFarcasterNetwork,
+        /// The verdict does not answer a pending engagement check.
+        EngagementCheckNotFound,
+        /// The collection already holds its `max_supply` of items.
+        MaxSupplyReached,
+        CollectionIdInUse,
+        ItemIdInUse,
+        /// The offer holds less than `MinOfferAmount`.
+        OfferTooLow,
+        /// The offer expires more than `MaxOfferDuration` blocks from now.
+        OfferTooLong,
+        /// The account already has `MaxPendingEngagementChecks` checks pending.
+        TooManyEngagementChecks,
+        /// The engagement check is younger than `EngagementCheckTimeout`.
+        EngagementCheckNotExpired,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register as an artist
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::default())]
+        #[pallet::weight(T::WeightInfo::register_artist())]
         pub fn register_artist(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -121,128 +755,252 @@ pub mod pallet {
                 Error::<T>::AlreadyRegistered
             );
 
-            // Define the registration fee
-            let registration_fee = T::Currency::minimum_balance() * 100u32.into();
-
-            // Ensure the artist has enough balance to pay the fee
             T::Currency::transfer(
                 &who,
-                &T::PalletId::get().into_account_truncating(),
-                registration_fee,
-                frame_support::traits::ExistenceRequirement::KeepAlive,
+                &Self::account_id(),
+                T::ArtistRegistrationFee::get(),
+                Preservation::Preserve,
             )?;
+            T::Currency::hold(&HoldReason::ArtistBond.into(), &who, T::ArtistBond::get())?;
 
-            Artists::<T>::insert(&who, true);
+            Artists::<T>::insert(&who, ArtistStatus::Pending);
             Self::deposit_event(Event::ArtistRegistered(who));
 
             Ok(())
         }
 
-        /// Create a new collection (artist only)
-        #[pallet::call_index(1)]
-        #[pallet::weight(Weight::default())]
-        pub fn create_collection(origin: OriginFor<T>, metadata: Vec<u8>) -> DispatchResult {
-            let creator = ensure_signed(origin)?;
+        /// Stop being an artist and get the bond back
+        ///
+        /// Only possible once all of the artist's collections are deleted. Releases the bond
+        /// actually held, whatever `ArtistBond` is now.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::deregister_artist())]
+        pub fn deregister_artist(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Artists::<T>::contains_key(&who),
+                Error::<T>::NotRegisteredArtist
+            );
+            ensure!(
+                ArtistCollectionCount::<T>::get(&who) == 0,
+                Error::<T>::ArtistHasCollections
+            );
 
-            ensure!(Artists::<T>::get(&creator), Error::<T>::NotRegisteredArtist);
+            Artists::<T>::remove(&who);
+            Self::remove_profile(&who);
+            T::Currency::release_all(&HoldReason::ArtistBond.into(), &who, Precision::BestEffort)?;
 
-            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
-                .try_into()
-                .map_err(|_| Error::<T>::MetadataInvalid)?;
+            Self::deposit_event(Event::ArtistDeregistered(who));
+            Ok(())
+        }
 
-            let collection_id = NextCollectionId::<T>::get();
+        /// Approve a pending or suspended artist
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::approve_artist())]
+        pub fn approve_artist(origin: OriginFor<T>, artist: T::AccountId) -> DispatchResult {
+            T::ArtistAdminOrigin::ensure_origin(origin)?;
+            Artists::<T>::try_mutate(&artist, |status| -> DispatchResult {
+                let status = status.as_mut().ok_or(Error::<T>::NotRegisteredArtist)?;
+                ensure!(
+                    *status != ArtistStatus::Verified,
+                    Error::<T>::ArtistAlreadyVerified
+                );
+                *status = ArtistStatus::Verified;
+                Ok(())
+            })?;
 
-            let collection = Collection {
-                creator: creator.clone(),
-                metadata: bounded_metadata,
-                nfts: BoundedVec::new(),
-            };
+            Self::deposit_event(Event::ArtistApproved(artist));
+            Ok(())
+        }
 
-            Collections::<T>::insert(collection_id, collection);
-            NextCollectionId::<T>::put(collection_id.saturating_add(1));
+        /// Suspend an artist, preventing them from creating collections
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::revoke_artist())]
+        pub fn revoke_artist(origin: OriginFor<T>, artist: T::AccountId) -> DispatchResult {
+            T::ArtistAdminOrigin::ensure_origin(origin)?;
+            Artists::<T>::try_mutate(&artist, |status| -> DispatchResult {
+                let status = status.as_mut().ok_or(Error::<T>::NotRegisteredArtist)?;
+                ensure!(
+                    *status != ArtistStatus::Suspended,
+                    Error::<T>::ArtistAlreadySuspended
+                );
+                *status = ArtistStatus::Suspended;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ArtistRevoked(artist));
+            Ok(())
+        }
+
+        /// Create a new collection (artist only)
+        ///
+        /// `royalty` of every sale goes to `royalty_recipient`, or to the creator when no
+        /// recipient is given. With a `max_supply`, the collection never holds more items than
+        /// that at once.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::create_collection(metadata.len() as u32))]
+        pub fn create_collection(
+            origin: OriginFor<T>,
+            metadata: Vec<u8>,
+            royalty: Perbill,
+            royalty_recipient: Option<T::AccountId>,
+            max_supply: Option<u32>,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+            Self::do_create_collection(
+                NextCollectionId::<T>::get(),
+                &creator,
+                metadata,
+                royalty,
+                royalty_recipient,
+                max_supply,
+            )
+        }
+
+        /// Replace the metadata of a collection (creator only)
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::update_collection_metadata(metadata.len() as u32))]
+        pub fn update_collection_metadata(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_set_collection_metadata(Some(&who), collection_id, metadata)
+        }
+
+        /// Freeze a collection (creator only)
+        ///
+        /// Blocks new items, metadata edits and burns until the collection is unfrozen.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::freeze_collection())]
+        pub fn freeze_collection(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::set_frozen(&who, collection_id, true)?;
+            Self::deposit_event(Event::CollectionFrozen(collection_id));
+            Ok(())
+        }
 
-            Self::deposit_event(Event::CollectionCreated(collection_id, creator));
+        /// Unfreeze a collection (creator only)
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::unfreeze_collection())]
+        pub fn unfreeze_collection(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::set_frozen(&who, collection_id, false)?;
+            Self::deposit_event(Event::CollectionUnfrozen(collection_id));
             Ok(())
         }
 
         /// Delete collection (creator only)
+        ///
+        /// Removes at most `RemoveItemsLimit` of the creator's items per call and freezes the
+        /// collection until the last call removes it. Refused before anything is removed while
+        /// anyone else owns an item of the collection or one of its items is in an auction. Only
+        /// the items actually removed are charged.
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::default())]
+        // The call wrapper `frame_support` generates converts the post info into itself
+        #[allow(clippy::useless_conversion)]
+        #[pallet::weight(T::WeightInfo::destroy_collection(T::RemoveItemsLimit::get()))]
         pub fn delete_collection(
             origin: OriginFor<T>,
             collection_id: CollectionId,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             let collection =
                 Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
             ensure!(collection.creator == who, Error::<T>::NotCollectionOwner);
 
-            Collections::<T>::remove(collection_id);
-            Self::deposit_event(Event::CollectionDeleted(collection_id));
-            Ok(())
+            let removed =
+                Self::remove_items_step(collection_id, collection, T::RemoveItemsLimit::get())?;
+            Ok(Some(T::WeightInfo::destroy_collection(removed)).into())
+        }
+
+        /// Destroy a collection in steps, with a witness of its size (creator only)
+        ///
+        /// `witness` must be at least the number of items left in the collection. Each call
+        /// removes at most `RemoveItemsLimit` items and freezes the collection until the call
+        /// that removes the last item also removes the collection. Only the items actually
+        /// removed are charged. Refused like `delete_collection`.
+        #[pallet::call_index(25)]
+        // The call wrapper `frame_support` generates converts the post info into itself
+        #[allow(clippy::useless_conversion)]
+        #[pallet::weight(T::WeightInfo::destroy_collection((*witness).min(T::RemoveItemsLimit::get())))]
+        pub fn destroy_collection(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            witness: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            ensure!(collection.creator == who, Error::<T>::NotCollectionOwner);
+            ensure!(collection.items <= witness, Error::<T>::BadWitness);
+
+            let removed = Self::remove_items_step(
+                collection_id,
+                collection,
+                witness.min(T::RemoveItemsLimit::get()),
+            )?;
+            Ok(Some(T::WeightInfo::destroy_collection(removed)).into())
         }
 
-        /// Mint a single NFT
+        /// Mint a single NFT (collection creator only)
         #[pallet::call_index(6)]
-        #[pallet::weight(Weight::default())]
+        #[pallet::weight(T::WeightInfo::create_nft(metadata.len() as u32))]
         pub fn create_nft(
             origin: OriginFor<T>,
             collection_id: CollectionId,
             metadata: Vec<u8>,
-            price: <T::Currency as Currency<T::AccountId>>::Balance,
+            price: BalanceOf<T>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
-            ensure!(
-                Collections::<T>::contains_key(collection_id),
-                Error::<T>::CollectionNotFound
-            );
-            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
-                .try_into()
-                .map_err(|_| Error::<T>::MetadataInvalid)?;
-
-            let item_id = NextItemId::<T>::get(collection_id);
-
-            let nft = NFT {
-                owner: sender.clone(),
-                metadata: bounded_metadata,
-                is_sold: false,
-                price,
-            };
+            Self::ensure_collection_not_frozen(collection_id)?;
 
-            // Insert the NFT into storage
-            Nfts::<T>::insert(collection_id, item_id, nft.clone());
+            let item_id = Self::do_create_nft(&sender, collection_id, metadata, price)?;
+            Self::deposit_event(Event::NFTMinted(collection_id, item_id, sender));
+            Ok(())
+        }
 
-            // Verify the NFT was properly stored
+        /// Mint several NFTs into a collection in one go (collection creator only)
+        ///
+        /// Items get contiguous ids starting at `NextItemId`. If any item fails, none is created.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::batch_create_nfts(items.len() as u32))]
+        pub fn batch_create_nfts(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            items: Vec<(Vec<u8>, BalanceOf<T>)>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(!items.is_empty(), Error::<T>::EmptyBatch);
             ensure!(
-                Nfts::<T>::contains_key(collection_id, item_id),
-                Error::<T>::NFTNotFound
+                items.len() <= T::MaxBatchSize::get() as usize,
+                Error::<T>::BatchTooLarge
             );
+            Self::ensure_collection_not_frozen(collection_id)?;
 
-            // Add the NFT ID to the collection
-            Collections::<T>::try_mutate(collection_id, |collection_option| -> DispatchResult {
-                let collection = collection_option
-                    .as_mut()
-                    .ok_or(Error::<T>::CollectionNotFound)?;
-                collection
-                    .nfts
-                    .try_push(item_id)
-                    .map_err(|_| Error::<T>::CollectionNotFound)?;
-                // Save the updated collection back to storage
-                Collections::<T>::insert(collection_id, collection.clone());
-                Ok(())
-            })?;
+            let item_ids = items
+                .into_iter()
+                .map(|(metadata, price)| {
+                    Self::do_create_nft(&sender, collection_id, metadata, price)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
 
-            // Increment the next item ID
-            let next_id = item_id.saturating_add(1);
-            NextItemId::<T>::insert(collection_id, next_id);
-            Self::deposit_event(Event::NFTMinted(collection_id, item_id, sender));
+            Self::deposit_event(Event::NFTBatchMinted(collection_id, item_ids, sender));
             Ok(())
         }
 
         /// Transfer an NFT from the caller to another account.
         #[pallet::call_index(7)]
-        #[pallet::weight(Weight::default())]
+        #[pallet::weight(T::WeightInfo::transfer_nft())]
         pub fn transfer_nft(
             origin: OriginFor<T>,
             collection_id: CollectionId,
@@ -250,14 +1008,10 @@ pub mod pallet {
             to: T::AccountId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::ensure_nft_owner(collection_id, item_id, &sender)?;
+            Self::ensure_not_in_auction(collection_id, item_id)?;
 
-            Nfts::<T>::try_mutate(collection_id, item_id, |nft_option| -> DispatchResult {
-                let nft = nft_option.as_mut().ok_or(Error::<T>::NFTNotFound)?;
-                ensure!(nft.owner == sender, Error::<T>::NotNFTOwner);
-                nft.owner = to.clone();
-                nft.is_sold = true;
-                Ok(())
-            })?;
+            Self::transfer_ownership(collection_id, item_id, &sender, &to, None)?;
 
             Self::deposit_event(Event::NFTTransferred(collection_id, item_id, sender, to));
             Ok(())
@@ -265,68 +1019,1962 @@ pub mod pallet {
 
         /// Burn an NFT (only if not sold)
         #[pallet::call_index(8)]
-        #[pallet::weight(Weight::default())]
+        #[pallet::weight(T::WeightInfo::burn_nft())]
         pub fn burn_nft(
             origin: OriginFor<T>,
             collection_id: CollectionId,
             item_id: ItemId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::do_burn(collection_id, item_id, Some(&sender))
+        }
 
-            // Check if the NFT exists and the caller is the owner
-            let nft = Nfts::<T>::get(collection_id, item_id).ok_or(Error::<T>::NFTNotFound)?;
-            ensure!(nft.owner == sender, Error::<T>::NotNFTOwner);
-            ensure!(!nft.is_sold, Error::<T>::NFTAlreadySold);
+        /// Buy a listed NFT from its owner at the listing price
+        ///
+        /// Items of a gated collection need an engagement `attestation` for the buyer.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::mint_nft())]
+        pub fn mint_nft(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            attestation: Option<EngagementAttestationOf<T>>,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
 
-            // Remove the NFT from storage
-            Nfts::<T>::remove(collection_id, item_id);
+            // Check if the NFT exists and is up for sale
+            let nft = Nfts::<T>::get(collection_id, item_id).ok_or(Error::<T>::NFTNotFound)?;
+            let price = Listings::<T>::take(collection_id, item_id).ok_or(Error::<T>::NotListed)?;
+            ensure!(nft.owner != buyer, Error::<T>::NotNFTOwner);
+            Self::ensure_engagement(&buyer, collection_id, attestation)?;
+
+            // Ensure the buyer has enough balance
+            let seller = nft.owner.clone();
+            Self::pay_for_item(&buyer, &seller, collection_id, item_id, price)?;
+
+            // Transfer ownership to the buyer
+            Self::transfer_ownership(collection_id, item_id, &seller, &buyer, Some(price))?;
+
+            Self::deposit_event(Event::NFTTransferred(collection_id, item_id, seller, buyer));
+            Ok(())
+        }
+
+        /// Put an owned NFT up for sale at a fixed price
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::list_nft())]
+        pub fn list_nft(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_nft_owner(collection_id, item_id, &who)?;
+            Self::ensure_not_in_auction(collection_id, item_id)?;
+            ensure!(
+                !Listings::<T>::contains_key(collection_id, item_id),
+                Error::<T>::AlreadyListed
+            );
+
+            Self::set_listing_price(collection_id, item_id, price);
+            Self::deposit_event(Event::NFTListed(collection_id, item_id, price));
+            Ok(())
+        }
+
+        /// Change the price of an active listing
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::update_price())]
+        pub fn update_price(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_nft_owner(collection_id, item_id, &who)?;
+            ensure!(
+                Listings::<T>::contains_key(collection_id, item_id),
+                Error::<T>::NotListed
+            );
+
+            Self::set_listing_price(collection_id, item_id, price);
+            Self::deposit_event(Event::NFTPriceUpdated(collection_id, item_id, price));
+            Ok(())
+        }
+
+        /// Take an NFT off the market
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::delist_nft())]
+        pub fn delist_nft(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_nft_owner(collection_id, item_id, &who)?;
+            Listings::<T>::take(collection_id, item_id).ok_or(Error::<T>::NotListed)?;
+
+            Self::deposit_event(Event::NFTDelisted(collection_id, item_id));
+            Ok(())
+        }
+
+        /// Change the royalty of a collection (creator only)
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_collection_royalty())]
+        pub fn set_collection_royalty(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            royalty: Perbill,
+            royalty_recipient: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+
+            Collections::<T>::try_mutate(collection_id, |collection_option| -> DispatchResult {
+                let collection = collection_option
+                    .as_mut()
+                    .ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(collection.creator == who, Error::<T>::NotCollectionOwner);
+                collection.royalty = royalty;
+                collection.royalty_recipient = royalty_recipient.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::CollectionRoyaltySet(
+                collection_id,
+                royalty,
+                royalty_recipient,
+            ));
+            Ok(())
+        }
+
+        /// Require buyers of a collection's items to prove engagement with a cast (creator only)
+        ///
+        /// `None` removes the requirement.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::set_collection_gating())]
+        pub fn set_collection_gating(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            rule: Option<GatingRule>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            ensure!(collection.creator == who, Error::<T>::NotCollectionOwner);
+
+            match &rule {
+                Some(rule) => CollectionGating::<T>::insert(collection_id, rule),
+                None => CollectionGating::<T>::remove(collection_id),
+            }
+            Self::deposit_event(Event::CollectionGatingSet(collection_id, rule));
+            Ok(())
+        }
+
+        /// Ask the offchain worker to check the caller's reactions to a cast on a Farcaster Hub
+        ///
+        /// `fid` must be linked to the caller, and `cast_fid` is the FID that posted the cast.
+        /// Once verified, the reactions satisfy the gating rules of collections without an
+        /// attestation. An account has at most `MaxPendingEngagementChecks` checks pending, and
+        /// a check unanswered after `EngagementCheckTimeout` blocks is dropped.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::request_engagement_check())]
+        pub fn request_engagement_check(
+            origin: OriginFor<T>,
+            fid: Fid,
+            cast_fid: Fid,
+            cast_hash: CastHash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(FidOf::<T>::get(&who) == Some(fid), Error::<T>::FidNotLinked);
+            ensure!(
+                !PendingEngagementChecks::<T>::contains_key(fid, cast_hash),
+                Error::<T>::EngagementCheckPending
+            );
+
+            PendingEngagementCheckCount::<T>::try_mutate(&who, |count| -> DispatchResult {
+                ensure!(
+                    *count < T::MaxPendingEngagementChecks::get(),
+                    Error::<T>::TooManyEngagementChecks
+                );
+                *count += 1;
+                Ok(())
+            })?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            PendingEngagementChecks::<T>::insert(
+                fid,
+                cast_hash,
+                PendingEngagementCheck {
+                    requester: who,
+                    cast_fid,
+                    requested_at: now,
+                },
+            );
+            Self::deposit_event(Event::EngagementCheckRequested(fid, cast_hash));
+            Ok(())
+        }
+
+        /// Record a like or recast from a signed Farcaster `ReactionAdd` message
+        ///
+        /// The message is the protobuf encoding served by Hubs. Its hash and signature are checked
+        /// on chain and the signer must be registered for the reacting FID, so anyone can submit
+        /// it.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::submit_reaction())]
+        pub fn submit_reaction(origin: OriginFor<T>, message: Vec<u8>) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                message.len() <= MAX_MESSAGE_LENGTH as usize,
+                Error::<T>::InvalidFarcasterMessage
+            );
+            let message =
+                farcaster_primitives::verify_message(&message).map_err(|err| match err {
+                    MessageError::UnsupportedMessageType => Error::<T>::NotAReaction,
+                    _ => Error::<T>::InvalidFarcasterMessage,
+                })?;
+            // Reactions to URLs carry no cast to gate on
+            let MessageBody::ReactionAdd(ReactionBody {
+                kind,
+                target: ReactionTarget::Cast(cast),
+            }) = message.body
+            else {
+                return Err(Error::<T>::NotAReaction.into());
+            };
+            ensure!(
+                message.network == FarcasterNetwork::Mainnet,
+                Error::<T>::WrongFarcasterNetwork
+            );
+            ensure!(
+                Self::is_fid_signer(message.fid, message.signer),
+                Error::<T>::UnknownFidSigner
+            );
+
+            let actions = VerifiedEngagements::<T>::mutate(message.fid, cast.hash, |actions| {
+                let actions = actions.get_or_insert_with(Default::default);
+                match kind {
+                    ReactionType::Like => actions.like = true,
+                    ReactionType::Recast => actions.recast = true,
+                }
+                *actions
+            });
+            Self::deposit_event(Event::EngagementVerified(message.fid, cast.hash, actions));
+            Ok(())
+        }
+
+        /// Record the outcome of a pending engagement check (offchain worker only)
+        ///
+        /// Submitted as an unsigned transaction; `signature` is made by `verdict.attester` over
+        /// [`Pallet::engagement_verdict_payload`]. The reactions found add to those already
+        /// verified.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::submit_engagement_verdict())]
+        pub fn submit_engagement_verdict(
+            origin: OriginFor<T>,
+            verdict: EngagementVerdictOf<T>,
+            signature: ed25519::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            Self::check_verdict(&verdict, &signature)?;
+
+            Self::take_engagement_check(verdict.fid, verdict.cast_hash)?;
+            let actions =
+                VerifiedEngagements::<T>::mutate(verdict.fid, verdict.cast_hash, |actions| {
+                    let actions = actions.get_or_insert_with(Default::default);
+                    *actions = actions.merge(verdict.actions);
+                    *actions
+                });
+            Self::deposit_event(Event::EngagementVerified(
+                verdict.fid,
+                verdict.cast_hash,
+                actions,
+            ));
+            Ok(())
+        }
+
+        /// Drop an engagement check left unanswered for `EngagementCheckTimeout` blocks
+        ///
+        /// Anyone can call this to free the requester's slot.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::clear_expired_engagement_check())]
+        pub fn clear_expired_engagement_check(
+            origin: OriginFor<T>,
+            fid: Fid,
+            cast_hash: CastHash,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let check = PendingEngagementChecks::<T>::get(fid, cast_hash)
+                .ok_or(Error::<T>::EngagementCheckNotFound)?;
+            ensure!(
+                Self::engagement_check_expired(&check, frame_system::Pallet::<T>::block_number()),
+                Error::<T>::EngagementCheckNotExpired
+            );
+
+            Self::take_engagement_check(fid, cast_hash)?;
+            Self::deposit_event(Event::EngagementCheckExpired(fid, cast_hash));
+            Ok(())
+        }
+
+        /// Withdraw collected fees from the pallet account (admin only)
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::withdraw_fees())]
+        pub fn withdraw_fees(
+            origin: OriginFor<T>,
+            dest: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            T::Currency::transfer(&Self::account_id(), &dest, amount, Preservation::Preserve)?;
+
+            Self::deposit_event(Event::FeesWithdrawn(dest, amount));
+            Ok(())
+        }
+
+        /// Auction an owned, unlisted NFT for `duration` blocks
+        ///
+        /// The item is locked until the auction is settled or cancelled.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::create_auction())]
+        pub fn create_auction(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            reserve: BalanceOf<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let seller = ensure_signed(origin)?;
+            Self::ensure_nft_owner(collection_id, item_id, &seller)?;
+            Self::ensure_not_in_auction(collection_id, item_id)?;
+            ensure!(
+                !Listings::<T>::contains_key(collection_id, item_id),
+                Error::<T>::AlreadyListed
+            );
+            ensure!(
+                duration >= T::MinAuctionDuration::get()
+                    && duration <= T::MaxAuctionDuration::get(),
+                Error::<T>::InvalidAuctionDuration
+            );
+
+            let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+            AuctionsEndingAt::<T>::try_mutate(end, |ending| {
+                ending.try_push((collection_id, item_id))
+            })
+            .map_err(|_| Error::<T>::AuctionScheduleFull)?;
+            Auctions::<T>::insert(
+                collection_id,
+                item_id,
+                Auction {
+                    seller,
+                    reserve,
+                    end,
+                    highest_bid: None,
+                },
+            );
+
+            Self::deposit_event(Event::AuctionCreated(collection_id, item_id, reserve, end));
+            Ok(())
+        }
+
+        /// Bid on a running auction
+        ///
+        /// The bid is held from the caller until they are outbid or the auction is settled. Items
+        /// of a gated collection need an engagement `attestation` for the bidder.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::bid())]
+        pub fn bid(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            amount: BalanceOf<T>,
+            attestation: Option<EngagementAttestationOf<T>>,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+            let mut auction =
+                Auctions::<T>::get(collection_id, item_id).ok_or(Error::<T>::AuctionNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now < auction.end, Error::<T>::AuctionEnded);
+            ensure!(bidder != auction.seller, Error::<T>::SellerCannotBid);
+            ensure!(amount >= auction.reserve, Error::<T>::BidBelowReserve);
+            Self::ensure_engagement(&bidder, collection_id, attestation)?;
+
+            if let Some((previous_bidder, previous_amount)) = auction.highest_bid.take() {
+                ensure!(amount > previous_amount, Error::<T>::BidTooLow);
+                T::Currency::release(
+                    &HoldReason::AuctionBid.into(),
+                    &previous_bidder,
+                    previous_amount,
+                    Precision::BestEffort,
+                )?;
+            }
+            T::Currency::hold(&HoldReason::AuctionBid.into(), &bidder, amount)?;
+            auction.highest_bid = Some((bidder.clone(), amount));
+
+            let anti_sniping = T::AntiSnipingPeriod::get();
+            if auction.end.saturating_sub(now) < anti_sniping {
+                let new_end = now.saturating_add(anti_sniping);
+                AuctionsEndingAt::<T>::mutate(auction.end, |ending| {
+                    ending.retain(|key| *key != (collection_id, item_id))
+                });
+                AuctionsEndingAt::<T>::try_mutate(new_end, |ending| {
+                    ending.try_push((collection_id, item_id))
+                })
+                .map_err(|_| Error::<T>::AuctionScheduleFull)?;
+                auction.end = new_end;
+                Self::deposit_event(Event::AuctionExtended(collection_id, item_id, new_end));
+            }
+
+            Auctions::<T>::insert(collection_id, item_id, auction);
+            Self::deposit_event(Event::BidPlaced(collection_id, item_id, bidder, amount));
+            Ok(())
+        }
+
+        /// Cancel an auction that has not received any bids (seller only)
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::cancel_auction())]
+        pub fn cancel_auction(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let auction =
+                Auctions::<T>::get(collection_id, item_id).ok_or(Error::<T>::AuctionNotFound)?;
+            ensure!(auction.seller == who, Error::<T>::NotNFTOwner);
+            ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+            Auctions::<T>::remove(collection_id, item_id);
+            AuctionsEndingAt::<T>::mutate(auction.end, |ending| {
+                ending.retain(|key| *key != (collection_id, item_id))
+            });
+
+            Self::deposit_event(Event::AuctionCancelled(collection_id, item_id));
+            Ok(())
+        }
+
+        /// Offer to buy an NFT, holding `amount` until the offer is accepted or withdrawn
+        ///
+        /// A new offer from the same buyer replaces the previous one. An offer holds at least
+        /// `MinOfferAmount` and expires within `MaxOfferDuration` blocks, and an item takes at
+        /// most `MaxOffersPerItem` offers. Items of a gated collection need an engagement
+        /// `attestation` for the buyer.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::make_offer())]
+        pub fn make_offer(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            amount: BalanceOf<T>,
+            expiry: BlockNumberFor<T>,
+            attestation: Option<EngagementAttestationOf<T>>,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let nft = Nfts::<T>::get(collection_id, item_id).ok_or(Error::<T>::NFTNotFound)?;
+            ensure!(nft.owner != buyer, Error::<T>::CannotOfferOnOwnNft);
+            ensure!(amount >= T::MinOfferAmount::get(), Error::<T>::OfferTooLow);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(expiry >= now, Error::<T>::OfferExpired);
+            ensure!(
+                expiry <= now.saturating_add(T::MaxOfferDuration::get()),
+                Error::<T>::OfferTooLong
+            );
+            Self::ensure_engagement(&buyer, collection_id, attestation)?;
+
+            if let Some(previous) = Offers::<T>::take((collection_id, item_id, &buyer)) {
+                T::Currency::release(
+                    &HoldReason::Offer.into(),
+                    &buyer,
+                    previous.amount,
+                    Precision::BestEffort,
+                )?;
+            } else {
+                OfferCount::<T>::try_mutate(collection_id, item_id, |count| -> DispatchResult {
+                    ensure!(
+                        *count < T::MaxOffersPerItem::get(),
+                        Error::<T>::TooManyOffers
+                    );
+                    *count += 1;
+                    Ok(())
+                })?;
+            }
+            T::Currency::hold(&HoldReason::Offer.into(), &buyer, amount)?;
+            Offers::<T>::insert((collection_id, item_id, &buyer), Offer { amount, expiry });
+
+            Self::deposit_event(Event::OfferMade(
+                collection_id,
+                item_id,
+                buyer,
+                amount,
+                expiry,
+            ));
+            Ok(())
+        }
+
+        /// Withdraw an offer and release the held funds
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::withdraw_offer())]
+        pub fn withdraw_offer(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let offer = Self::take_offer(collection_id, item_id, &buyer)?;
+            T::Currency::release(
+                &HoldReason::Offer.into(),
+                &buyer,
+                offer.amount,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::OfferWithdrawn(collection_id, item_id, buyer));
+            Ok(())
+        }
+
+        /// Accept an open offer, swapping the held funds for ownership (owner only)
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::accept_offer())]
+        pub fn accept_offer(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            buyer: T::AccountId,
+        ) -> DispatchResult {
+            let seller = ensure_signed(origin)?;
+            Self::ensure_nft_owner(collection_id, item_id, &seller)?;
+            Self::ensure_not_in_auction(collection_id, item_id)?;
+            let offer = Self::take_offer(collection_id, item_id, &buyer)?;
+            ensure!(
+                offer.expiry >= frame_system::Pallet::<T>::block_number(),
+                Error::<T>::OfferExpired
+            );
+
+            T::Currency::release(
+                &HoldReason::Offer.into(),
+                &buyer,
+                offer.amount,
+                Precision::Exact,
+            )?;
+            Self::pay_for_item(&buyer, &seller, collection_id, item_id, offer.amount)?;
+            Self::transfer_ownership(collection_id, item_id, &seller, &buyer, Some(offer.amount))?;
+
+            Self::deposit_event(Event::NFTTransferred(
+                collection_id,
+                item_id,
+                seller.clone(),
+                buyer.clone(),
+            ));
+            Self::deposit_event(Event::OfferAccepted(
+                collection_id,
+                item_id,
+                seller,
+                buyer,
+                offer.amount,
+            ));
+            Ok(())
+        }
+
+        /// Release the funds of an expired offer
+        ///
+        /// Anyone can call this to clean up offers the buyer left behind.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::clear_expired_offer())]
+        pub fn clear_expired_offer(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            buyer: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let offer = Offers::<T>::get((collection_id, item_id, &buyer))
+                .ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(
+                offer.expiry < frame_system::Pallet::<T>::block_number(),
+                Error::<T>::OfferNotExpired
+            );
+
+            Self::take_offer(collection_id, item_id, &buyer)?;
+            T::Currency::release(
+                &HoldReason::Offer.into(),
+                &buyer,
+                offer.amount,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::OfferExpired(collection_id, item_id, buyer));
+            Ok(())
+        }
+
+        /// Set or replace the caller's artist profile (registered artists only)
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::set_profile())]
+        pub fn set_profile(
+            origin: OriginFor<T>,
+            display_name: Vec<u8>,
+            avatar_cid: Vec<u8>,
+            links: Vec<Vec<u8>>,
+            fid: Option<Fid>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Artists::<T>::contains_key(&who),
+                Error::<T>::NotRegisteredArtist
+            );
+
+            let links = links
+                .into_iter()
+                .map(|link| link.try_into().map_err(|_| Error::<T>::ProfileFieldTooLong))
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyLinks)?;
+            let profile = ArtistProfile {
+                display_name: display_name
+                    .try_into()
+                    .map_err(|_| Error::<T>::ProfileFieldTooLong)?,
+                avatar_cid: avatar_cid
+                    .try_into()
+                    .map_err(|_| Error::<T>::ProfileFieldTooLong)?,
+                links,
+                fid,
+            };
+
+            if let Some(fid) = fid {
+                ensure!(FidOf::<T>::get(&who) == Some(fid), Error::<T>::FidNotLinked);
+            }
+            Self::remove_profile(&who);
+            if let Some(fid) = fid {
+                FidToArtist::<T>::insert(fid, &who);
+            }
+            ArtistProfiles::<T>::insert(&who, profile);
+
+            Self::deposit_event(Event::ArtistProfileSet(who));
+            Ok(())
+        }
+
+        /// Link the caller's account to a Farcaster FID
+        ///
+        /// `signature` is made by one of the FID's signer keys over
+        /// [`Pallet::fid_link_payload`] for the caller and their current nonce. A previous link of
+        /// either the FID or the account is replaced.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::link_fid())]
+        pub fn link_fid(
+            origin: OriginFor<T>,
+            fid: Fid,
+            signer: ed25519::Public,
+            signature: ed25519::Signature,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::is_fid_signer(fid, signer),
+                Error::<T>::UnknownFidSigner
+            );
+            let nonce = FidLinkNonce::<T>::get(&who);
+            ensure!(
+                sp_io::crypto::ed25519_verify(
+                    &signature,
+                    &Self::fid_link_payload(fid, &who, nonce),
+                    &signer
+                ),
+                Error::<T>::BadFidSignature
+            );
+            FidLinkNonce::<T>::insert(&who, nonce.saturating_add(1));
+
+            if let Some(previous_fid) = FidOf::<T>::get(&who) {
+                if previous_fid != fid {
+                    Self::unlink(&who, previous_fid);
+                }
+            }
+            if let Some(previous_account) = AccountOfFid::<T>::get(fid) {
+                if previous_account != who {
+                    Self::unlink(&previous_account, fid);
+                }
+            }
+            FidOf::<T>::insert(&who, fid);
+            AccountOfFid::<T>::insert(fid, &who);
+
+            Self::deposit_event(Event::FidLinked(fid, who));
+            Ok(())
+        }
+
+        /// Remove the caller's FID link
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::unlink_fid())]
+        pub fn unlink_fid(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let fid = FidOf::<T>::get(&who).ok_or(Error::<T>::FidNotLinked)?;
+
+            Self::unlink(&who, fid);
+            Ok(())
+        }
+
+        /// Remove the caller's artist profile and release its FID
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::clear_profile())]
+        pub fn clear_profile(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                ArtistProfiles::<T>::contains_key(&who),
+                Error::<T>::ProfileNotFound
+            );
+
+            Self::remove_profile(&who);
+            Self::deposit_event(Event::ArtistProfileCleared(who));
+            Ok(())
+        }
+
+        /// Replace the metadata of an item (collection creator only)
+        ///
+        /// Only possible while the creator still owns the item, before its first sale and before
+        /// the metadata is locked.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::update_nft_metadata(metadata.len() as u32))]
+        pub fn update_nft_metadata(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_set_item_metadata(Some(&who), collection_id, item_id, metadata)
+        }
+
+        /// Permanently lock the metadata of an item (collection creator only)
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::lock_nft_metadata())]
+        pub fn lock_nft_metadata(
+            origin: OriginFor<T>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_metadata_editable(&who, collection_id, item_id)?;
+
+            MetadataLocked::<T>::insert(collection_id, item_id, ());
+            Self::deposit_event(Event::NFTMetadataLocked(collection_id, item_id));
+            Ok(())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let ending = AuctionsEndingAt::<T>::take(now);
+            let settled = ending.len() as u32;
+            for (collection_id, item_id) in ending {
+                Self::settle_auction(collection_id, item_id);
+            }
+
+            T::WeightInfo::settle_auctions(settled)
+        }
+
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            Self::process_engagement_checks(now);
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::submit_engagement_verdict { verdict, signature } = call else {
+                return InvalidTransaction::Call.into();
+            };
+            match Self::check_verdict(verdict, signature) {
+                Ok(()) => {}
+                Err(Error::<T>::EngagementCheckNotFound) => {
+                    return InvalidTransaction::Stale.into()
+                }
+                Err(_) => return InvalidTransaction::BadProof.into(),
+            }
+
+            ValidTransaction::with_tag_prefix("NftEngagementVerdict")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((verdict.fid, verdict.cast_hash, verdict.requested_at))
+                .longevity(ENGAGEMENT_CHECK_RETRY.into())
+                .propagate(true)
+                .build()
+        }
+    }
+
+    /// Upper bound on the number of entries returned by a single paginated query.
+    pub const MAX_PAGE_SIZE: u32 = 256;
+
+    /// Longest artist display name, in bytes.
+    pub const MAX_NAME_LENGTH: u32 = 64;
+    /// Longest avatar CID, in bytes.
+    pub const MAX_CID_LENGTH: u32 = 128;
+    /// Longest profile link, in bytes.
+    pub const MAX_LINK_LENGTH: u32 = 256;
+    /// Most links on a profile.
+    pub const MAX_LINKS: u32 = 5;
+
+    /// Context prefixed to every `link_fid` payload.
+    pub const FID_LINK_CONTEXT: &[u8] = b"polkadot-nft-farcaster-frames:link_fid";
+    /// Context prefixed to every engagement attestation payload.
+    pub const ENGAGEMENT_CONTEXT: &[u8] = b"polkadot-nft-farcaster-frames:engagement";
+    /// Context prefixed to every engagement verdict payload.
+    pub const ENGAGEMENT_VERDICT_CONTEXT: &[u8] =
+        b"polkadot-nft-farcaster-frames:engagement_verdict";
+
+    /// Longest Farcaster message accepted by `submit_reaction`, in bytes.
+    pub const MAX_MESSAGE_LENGTH: u32 = 1024;
+
+    /// Key type of the attester keys the offchain worker signs verdicts with.
+    pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"nfte");
+    /// Blocks the offchain worker waits for a submitted verdict before checking again.
+    pub const ENGAGEMENT_CHECK_RETRY: u32 = 10;
+
+    impl<T: Config> Pallet<T> {
+        /// Ensure `who` owns the given NFT.
+        fn ensure_nft_owner(
+            collection_id: CollectionId,
+            item_id: ItemId,
+            who: &T::AccountId,
+        ) -> Result<NftOf<T>, DispatchError> {
+            let nft = Nfts::<T>::get(collection_id, item_id).ok_or(Error::<T>::NFTNotFound)?;
+            ensure!(nft.owner == *who, Error::<T>::NotNFTOwner);
+            Ok(nft)
+        }
+
+        /// Store a new item of `sender`'s collection at its next free id and list it at `price`.
+        fn do_create_nft(
+            sender: &T::AccountId,
+            collection_id: CollectionId,
+            metadata: Vec<u8>,
+            price: BalanceOf<T>,
+        ) -> Result<ItemId, DispatchError> {
+            let collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            ensure!(
+                collection.creator == *sender,
+                Error::<T>::NotCollectionOwner
+            );
+
+            let item_id = NextItemId::<T>::get(collection_id);
+            Self::do_mint(sender, collection_id, item_id, metadata, price)?;
+            // New items go on sale straight away at the creation price
+            Listings::<T>::insert(collection_id, item_id, price);
+            Ok(item_id)
+        }
+
+        /// Store a new, unlisted item owned by `owner` under a free `item_id`.
+        ///
+        /// The item deposit is held from `owner`. The caller checks the collection is not
+        /// frozen.
+        pub(crate) fn do_mint(
+            owner: &T::AccountId,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            metadata: Vec<u8>,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(
+                !Nfts::<T>::contains_key(collection_id, item_id),
+                Error::<T>::ItemIdInUse
+            );
+            let mut collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            ensure!(
+                collection
+                    .max_supply
+                    .is_none_or(|max| collection.items < max),
+                Error::<T>::MaxSupplyReached
+            );
+            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
+                .try_into()
+                .map_err(|_| Error::<T>::MetadataInvalid)?;
+
+            let deposit = Self::deposit_for(T::ItemDeposit::get(), bounded_metadata.len());
+            T::Currency::hold(&HoldReason::ItemDeposit.into(), owner, deposit)?;
+            ItemDeposits::<T>::insert(collection_id, item_id, deposit);
+
+            let nft = NFT {
+                owner: owner.clone(),
+                metadata: bounded_metadata,
+                is_sold: false,
+                price,
+            };
+            Nfts::<T>::insert(collection_id, item_id, nft);
+            OwnedNfts::<T>::insert(owner, (collection_id, item_id), ());
+            if *owner != collection.creator {
+                ForeignItems::<T>::mutate(collection_id, |count| *count = count.saturating_add(1));
+            }
+
+            // Count the NFT in its collection
+            collection.items = collection.items.saturating_add(1);
+            Collections::<T>::insert(collection_id, collection);
+
+            // Keep the next item id past every id in use
+            NextItemId::<T>::mutate(collection_id, |next_id| {
+                *next_id = (*next_id).max(item_id.saturating_add(1))
+            });
+            Ok(())
+        }
+
+        /// Create a collection of `creator` under a free `collection_id`.
+        pub(crate) fn do_create_collection(
+            collection_id: CollectionId,
+            creator: &T::AccountId,
+            metadata: Vec<u8>,
+            royalty: Perbill,
+            royalty_recipient: Option<T::AccountId>,
+            max_supply: Option<u32>,
+        ) -> DispatchResult {
+            let status = Artists::<T>::get(creator).ok_or(Error::<T>::NotRegisteredArtist)?;
+            ensure!(
+                status == ArtistStatus::Verified,
+                Error::<T>::ArtistNotVerified
+            );
+            ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+            ensure!(
+                !Collections::<T>::contains_key(collection_id),
+                Error::<T>::CollectionIdInUse
+            );
+
+            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
+                .try_into()
+                .map_err(|_| Error::<T>::MetadataInvalid)?;
+
+            let deposit = Self::deposit_for(T::CollectionDeposit::get(), bounded_metadata.len());
+            T::Currency::hold(&HoldReason::CollectionDeposit.into(), creator, deposit)?;
+
+            CollectionDeposits::<T>::insert(collection_id, deposit);
+            let collection = Collection {
+                creator: creator.clone(),
+                metadata: bounded_metadata,
+                items: 0,
+                max_supply,
+                royalty,
+                royalty_recipient: royalty_recipient.unwrap_or_else(|| creator.clone()),
+                is_frozen: false,
+            };
+
+            Collections::<T>::insert(collection_id, collection);
+            NextCollectionId::<T>::mutate(|next_id| {
+                *next_id = (*next_id).max(collection_id.saturating_add(1))
+            });
+            ArtistCollectionCount::<T>::mutate(creator, |count| *count = count.saturating_add(1));
+
+            Self::deposit_event(Event::CollectionCreated(collection_id, creator.clone()));
+            Ok(())
+        }
+
+        /// Replace the metadata of a collection, checking the caller created it when
+        /// `maybe_check_creator` is given.
+        pub(crate) fn do_set_collection_metadata(
+            maybe_check_creator: Option<&T::AccountId>,
+            collection_id: CollectionId,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
+                .try_into()
+                .map_err(|_| Error::<T>::MetadataInvalid)?;
 
-            // Remove the NFT ID from the collection
             Collections::<T>::try_mutate(collection_id, |collection_option| -> DispatchResult {
                 let collection = collection_option
                     .as_mut()
                     .ok_or(Error::<T>::CollectionNotFound)?;
-                collection.nfts.retain(|&id| id != item_id);
+                if let Some(who) = maybe_check_creator {
+                    ensure!(collection.creator == *who, Error::<T>::NotCollectionOwner);
+                }
+                ensure!(!collection.is_frozen, Error::<T>::CollectionFrozen);
+                let deposit =
+                    Self::deposit_for(T::CollectionDeposit::get(), bounded_metadata.len());
+                Self::adjust_deposit(
+                    HoldReason::CollectionDeposit,
+                    &collection.creator,
+                    CollectionDeposits::<T>::get(collection_id),
+                    deposit,
+                )?;
+                CollectionDeposits::<T>::insert(collection_id, deposit);
+                collection.metadata = bounded_metadata;
                 Ok(())
             })?;
 
-            Self::deposit_event(Event::NFTBurned(collection_id, item_id, sender));
+            Self::deposit_event(Event::CollectionUpdated(collection_id));
             Ok(())
         }
 
-        /// Buy an NFT from its owner
-        #[pallet::call_index(9)]
-        #[pallet::weight(Weight::default())]
-        pub fn mint_nft(
-            origin: OriginFor<T>,
+        /// Delete a collection and all of its items, returning how many items were removed.
+        ///
+        /// Checks the caller created the collection when `maybe_check_creator` is given.
+        pub(crate) fn do_destroy_collection(
+            collection_id: CollectionId,
+            witness: u32,
+            maybe_check_creator: Option<&T::AccountId>,
+        ) -> Result<u32, DispatchError> {
+            let mut collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            if let Some(who) = maybe_check_creator {
+                ensure!(collection.creator == *who, Error::<T>::NotCollectionOwner);
+            }
+            let items = collection.items;
+            ensure!(items <= witness, Error::<T>::BadWitness);
+            Self::ensure_only_creator_items(collection_id)?;
+
+            Self::remove_items(collection_id, &mut collection, items as usize);
+            Self::remove_collection(collection_id, &collection.creator);
+            Ok(items)
+        }
+
+        /// Burn an item that was never sold, checking the caller owns it when
+        /// `maybe_check_owner` is given.
+        pub(crate) fn do_burn(
             collection_id: CollectionId,
             item_id: ItemId,
+            maybe_check_owner: Option<&T::AccountId>,
         ) -> DispatchResult {
-            let buyer = ensure_signed(origin)?;
+            let nft = Nfts::<T>::get(collection_id, item_id).ok_or(Error::<T>::NFTNotFound)?;
+            if let Some(who) = maybe_check_owner {
+                ensure!(nft.owner == *who, Error::<T>::NotNFTOwner);
+            }
+            ensure!(!nft.is_sold, Error::<T>::NFTAlreadySold);
+            Self::ensure_not_in_auction(collection_id, item_id)?;
+            let mut collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            ensure!(!collection.is_frozen, Error::<T>::CollectionFrozen);
+
+            // Remove the NFT from storage
+            Self::remove_item(collection_id, item_id, &nft.owner);
+
+            // Drop the NFT from the collection's item count
+            collection.items = collection.items.saturating_sub(1);
+            if nft.owner != collection.creator {
+                ForeignItems::<T>::mutate(collection_id, |count| *count = count.saturating_sub(1));
+            }
+            Collections::<T>::insert(collection_id, collection);
+
+            Self::deposit_event(Event::NFTBurned(collection_id, item_id, nft.owner));
+            Ok(())
+        }
+
+        /// Replace the metadata of an item. With `maybe_check_creator`, the caller must have
+        /// created the collection and still own the item before its first sale.
+        ///
+        /// Locked metadata and frozen collections are refused either way. The deposit is
+        /// adjusted on the item's owner.
+        pub(crate) fn do_set_item_metadata(
+            maybe_check_creator: Option<&T::AccountId>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            match maybe_check_creator {
+                Some(who) => Self::ensure_metadata_editable(who, collection_id, item_id)?,
+                None => {
+                    Self::ensure_collection_not_frozen(collection_id)?;
+                    ensure!(
+                        !MetadataLocked::<T>::contains_key(collection_id, item_id),
+                        Error::<T>::MetadataLocked
+                    );
+                }
+            }
+            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
+                .try_into()
+                .map_err(|_| Error::<T>::MetadataInvalid)?;
+
+            Nfts::<T>::try_mutate(collection_id, item_id, |nft_option| -> DispatchResult {
+                let nft = nft_option.as_mut().ok_or(Error::<T>::NFTNotFound)?;
+                let deposit = Self::deposit_for(T::ItemDeposit::get(), bounded_metadata.len());
+                Self::adjust_deposit(
+                    HoldReason::ItemDeposit,
+                    &nft.owner,
+                    ItemDeposits::<T>::get(collection_id, item_id),
+                    deposit,
+                )?;
+                ItemDeposits::<T>::insert(collection_id, item_id, deposit);
+                nft.metadata = bounded_metadata;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::NFTMetadataUpdated(collection_id, item_id));
+            Ok(())
+        }
+
+        /// Ensure every item of the collection is owned by its creator and none is in an auction.
+        ///
+        /// Reads only the item counter and the first auction of the collection, so it is checked
+        /// in full before any item is removed.
+        fn ensure_only_creator_items(collection_id: CollectionId) -> DispatchResult {
+            ensure!(
+                ForeignItems::<T>::get(collection_id) == 0,
+                Error::<T>::ItemsOwnedByOthers
+            );
+            ensure!(
+                Auctions::<T>::iter_key_prefix(collection_id)
+                    .next()
+                    .is_none(),
+                Error::<T>::NftInAuction
+            );
+            Ok(())
+        }
+
+        /// Remove up to `limit` items of a collection owned only by its creator, returning how
+        /// many were removed.
+        ///
+        /// Freezes the collection while items are left, and removes it with the last item.
+        fn remove_items_step(
+            collection_id: CollectionId,
+            mut collection: Collection<T::AccountId>,
+            limit: u32,
+        ) -> Result<u32, DispatchError> {
+            Self::ensure_only_creator_items(collection_id)?;
+
+            let removed = Self::remove_items(collection_id, &mut collection, limit as usize);
+            if collection.items == 0 {
+                Self::remove_collection(collection_id, &collection.creator);
+            } else {
+                let left = collection.items;
+                collection.is_frozen = true;
+                Collections::<T>::insert(collection_id, collection);
+                Self::deposit_event(Event::CollectionItemsRemoved(collection_id, removed, left));
+            }
+            Ok(removed)
+        }
+
+        /// Remove up to `limit` of the collection's items, returning how many were removed.
+        ///
+        /// The caller checks the items are removable with [`Self::ensure_only_creator_items`]
+        /// and stores `collection` afterwards.
+        fn remove_items(
+            collection_id: CollectionId,
+            collection: &mut Collection<T::AccountId>,
+            limit: usize,
+        ) -> u32 {
+            let items: Vec<_> = Nfts::<T>::iter_prefix(collection_id).take(limit).collect();
+            for (item_id, nft) in items.iter() {
+                Self::remove_item(collection_id, *item_id, &nft.owner);
+            }
+            let count = items.len() as u32;
+            collection.items = collection.items.saturating_sub(count);
+            count
+        }
+
+        /// Remove an item with its listing and deposit, and cancel its open offers.
+        ///
+        /// Offers are keyed by item id, which can be reused, so none may outlive the item.
+        /// The caller keeps the collection's counters in step.
+        fn remove_item(collection_id: CollectionId, item_id: ItemId, owner: &T::AccountId) {
+            Nfts::<T>::remove(collection_id, item_id);
+            OwnedNfts::<T>::remove(owner, (collection_id, item_id));
+            Self::release_deposit(
+                HoldReason::ItemDeposit,
+                owner,
+                ItemDeposits::<T>::take(collection_id, item_id),
+            );
+            Listings::<T>::remove(collection_id, item_id);
+            MetadataLocked::<T>::remove(collection_id, item_id);
+            for (buyer, offer) in Offers::<T>::drain_prefix((collection_id, item_id)) {
+                Self::release_deposit(HoldReason::Offer, &buyer, offer.amount);
+            }
+            OfferCount::<T>::remove(collection_id, item_id);
+        }
+
+        /// Drop an emptied collection along with its item counter and release its deposit.
+        fn remove_collection(collection_id: CollectionId, creator: &T::AccountId) {
+            Collections::<T>::remove(collection_id);
+            ArtistCollectionCount::<T>::mutate(creator, |count| *count = count.saturating_sub(1));
+            Self::release_deposit(
+                HoldReason::CollectionDeposit,
+                creator,
+                CollectionDeposits::<T>::take(collection_id),
+            );
+            NextItemId::<T>::remove(collection_id);
+            ForeignItems::<T>::remove(collection_id);
+            CollectionGating::<T>::remove(collection_id);
+            Self::deposit_event(Event::CollectionDeleted(collection_id));
+        }
+
+        /// Ensure the collection exists and is not frozen.
+        pub(crate) fn ensure_collection_not_frozen(collection_id: CollectionId) -> DispatchResult {
+            let collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            ensure!(!collection.is_frozen, Error::<T>::CollectionFrozen);
+            Ok(())
+        }
+
+        /// Set the frozen flag of a collection created by `who`.
+        fn set_frozen(
+            who: &T::AccountId,
+            collection_id: CollectionId,
+            frozen: bool,
+        ) -> DispatchResult {
+            Collections::<T>::try_mutate(collection_id, |collection_option| -> DispatchResult {
+                let collection = collection_option
+                    .as_mut()
+                    .ok_or(Error::<T>::CollectionNotFound)?;
+                ensure!(collection.creator == *who, Error::<T>::NotCollectionOwner);
+                if frozen {
+                    ensure!(!collection.is_frozen, Error::<T>::CollectionFrozen);
+                } else {
+                    ensure!(collection.is_frozen, Error::<T>::CollectionNotFrozen);
+                }
+                collection.is_frozen = frozen;
+                Ok(())
+            })
+        }
 
-            // Check if the NFT exists and is not already sold
+        /// Ensure `who` created the item's collection and may still change the item's metadata.
+        fn ensure_metadata_editable(
+            who: &T::AccountId,
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> DispatchResult {
+            let collection =
+                Collections::<T>::get(collection_id).ok_or(Error::<T>::CollectionNotFound)?;
+            ensure!(collection.creator == *who, Error::<T>::NotCollectionOwner);
+            ensure!(!collection.is_frozen, Error::<T>::CollectionFrozen);
             let nft = Nfts::<T>::get(collection_id, item_id).ok_or(Error::<T>::NFTNotFound)?;
+            ensure!(nft.owner == *who, Error::<T>::NotNFTOwner);
             ensure!(!nft.is_sold, Error::<T>::NFTAlreadySold);
-            ensure!(nft.owner != buyer, Error::<T>::NotNFTOwner);
+            ensure!(
+                !MetadataLocked::<T>::contains_key(collection_id, item_id),
+                Error::<T>::MetadataLocked
+            );
+            Ok(())
+        }
 
-            // Ensure the buyer has enough balance
-            let seller = nft.owner.clone();
-            T::Currency::transfer(
-                &buyer,
-                &seller,
-                nft.price,
-                frame_support::traits::ExistenceRequirement::KeepAlive,
-            )?;
+        /// Whether `signer` is a signer key of `fid` according to [`Config::FidSigners`].
+        pub fn is_fid_signer(fid: Fid, signer: ed25519::Public) -> bool {
+            T::FidSigners::contains(&(fid, signer))
+        }
 
-            // Transfer ownership to the buyer
+        /// Bytes an FID signer key signs to link `fid` to `account`.
+        ///
+        /// The payload is domain separated by a fixed context and this chain's genesis hash.
+        pub fn fid_link_payload(fid: Fid, account: &T::AccountId, nonce: u64) -> Vec<u8> {
+            let genesis = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            (FID_LINK_CONTEXT, genesis, fid, account, nonce).encode()
+        }
+
+        /// Bytes a trusted attester signs to state that `fid`, linked to `account`, made the
+        /// reactions of `rule`.
+        ///
+        /// Like [`Pallet::fid_link_payload`], it is bound to this chain and to the account's
+        /// current engagement nonce, so every attestation can be used once.
+        pub fn engagement_payload(
+            fid: Fid,
+            account: &T::AccountId,
+            rule: &GatingRule,
+            expiry: BlockNumberFor<T>,
+            nonce: u64,
+        ) -> Vec<u8> {
+            let genesis = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            (
+                ENGAGEMENT_CONTEXT,
+                genesis,
+                fid,
+                account,
+                rule,
+                expiry,
+                nonce,
+            )
+                .encode()
+        }
+
+        /// Ensure `who` may buy from the collection, consuming their engagement attestation if
+        /// the collection is gated.
+        fn ensure_engagement(
+            who: &T::AccountId,
+            collection_id: CollectionId,
+            attestation: Option<EngagementAttestationOf<T>>,
+        ) -> DispatchResult {
+            let Some(rule) = CollectionGating::<T>::get(collection_id) else {
+                return Ok(());
+            };
+            let Some(attestation) = attestation else {
+                // Fall back to reactions verified by the offchain worker or attested by the oracle
+                let verified = FidOf::<T>::get(who).map(|fid| {
+                    VerifiedEngagements::<T>::get(fid, rule.cast_hash)
+                        .unwrap_or_default()
+                        .merge(T::SocialAttestations::engagement(fid, &rule.cast_hash))
+                });
+                ensure!(
+                    verified.is_some_and(|actions| actions.covers(&rule.actions)),
+                    Error::<T>::EngagementRequired
+                );
+                return Ok(());
+            };
+            ensure!(
+                T::TrustedAttesters::contains(&attestation.attester),
+                Error::<T>::UntrustedAttester
+            );
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= attestation.expiry,
+                Error::<T>::AttestationExpired
+            );
+            let fid = FidOf::<T>::get(who).ok_or(Error::<T>::FidNotLinked)?;
+            let nonce = EngagementNonce::<T>::get(who);
+            ensure!(
+                sp_io::crypto::ed25519_verify(
+                    &attestation.signature,
+                    &Self::engagement_payload(fid, who, &rule, attestation.expiry, nonce),
+                    &attestation.attester
+                ),
+                Error::<T>::BadAttestation
+            );
+            EngagementNonce::<T>::insert(who, nonce.saturating_add(1));
+            Ok(())
+        }
+
+        /// Bytes the offchain worker signs to report `verdict`.
+        pub fn engagement_verdict_payload(verdict: &EngagementVerdictOf<T>) -> Vec<u8> {
+            let genesis = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            (ENGAGEMENT_VERDICT_CONTEXT, genesis, verdict).encode()
+        }
+
+        /// Remove a pending engagement check, keeping its requester's count in step.
+        fn take_engagement_check(
+            fid: Fid,
+            cast_hash: CastHash,
+        ) -> Result<PendingEngagementCheckOf<T>, DispatchError> {
+            let check = PendingEngagementChecks::<T>::take(fid, cast_hash)
+                .ok_or(Error::<T>::EngagementCheckNotFound)?;
+            PendingEngagementCheckCount::<T>::mutate_exists(&check.requester, |count| {
+                *count = count
+                    .map(|count| count.saturating_sub(1))
+                    .filter(|count| *count > 0)
+            });
+            Ok(check)
+        }
+
+        /// Whether `check` went unanswered for longer than `EngagementCheckTimeout` at `now`.
+        fn engagement_check_expired(
+            check: &PendingEngagementCheckOf<T>,
+            now: BlockNumberFor<T>,
+        ) -> bool {
+            now > check
+                .requested_at
+                .saturating_add(T::EngagementCheckTimeout::get())
+        }
+
+        /// Ensure `verdict` answers a pending check and is signed by a trusted attester.
+        fn check_verdict(
+            verdict: &EngagementVerdictOf<T>,
+            signature: &ed25519::Signature,
+        ) -> Result<(), Error<T>> {
+            ensure!(
+                PendingEngagementChecks::<T>::get(verdict.fid, verdict.cast_hash)
+                    .is_some_and(|check| check.requested_at == verdict.requested_at),
+                Error::<T>::EngagementCheckNotFound
+            );
+            ensure!(
+                T::TrustedAttesters::contains(&verdict.attester),
+                Error::<T>::UntrustedAttester
+            );
+            ensure!(
+                sp_io::crypto::ed25519_verify(
+                    signature,
+                    &Self::engagement_verdict_payload(verdict),
+                    &verdict.attester
+                ),
+                Error::<T>::BadAttestation
+            );
+            Ok(())
+        }
+
+        /// Query the Hub for pending engagement checks and submit the verdicts.
+        ///
+        /// Does nothing unless the node's keystore holds a trusted attester key.
+        fn process_engagement_checks(now: BlockNumberFor<T>) {
+            let Some(attester) = sp_io::crypto::ed25519_public_keys(KEY_TYPE)
+                .into_iter()
+                .find(T::TrustedAttesters::contains)
+            else {
+                return;
+            };
+            let hub = offchain::hub_url();
+
+            let mut checked = 0;
+            for (fid, cast_hash, check) in PendingEngagementChecks::<T>::iter() {
+                // Expired checks wait for `clear_expired_engagement_check`
+                if Self::engagement_check_expired(&check, now) {
+                    continue;
+                }
+                let requested_at = check.requested_at;
+                if checked >= T::MaxEngagementChecksPerBlock::get() {
+                    break;
+                }
+                // Skip checks whose verdict may still be on its way into a block
+                let key = (b"nft-marketplace::engagement-check", fid, cast_hash).encode();
+                let last_attempt = StorageValueRef::persistent(&key);
+                if let Ok(Some((at, attempted_at))) =
+                    last_attempt.get::<(BlockNumberFor<T>, BlockNumberFor<T>)>()
+                {
+                    if at == requested_at
+                        && now < attempted_at.saturating_add(ENGAGEMENT_CHECK_RETRY.into())
+                    {
+                        continue;
+                    }
+                }
+                last_attempt.set(&(requested_at, now));
+                checked += 1;
+
+                let actions =
+                    match offchain::fetch_engagement(&hub, fid, check.cast_fid, &cast_hash) {
+                        Ok(actions) => actions,
+                        Err(err) => {
+                            log::warn!(
+                                target: LOG_TARGET,
+                                "engagement check of FID {:?} failed: {:?}",
+                                fid,
+                                err,
+                            );
+                            continue;
+                        }
+                    };
+                let verdict = EngagementVerdict {
+                    fid,
+                    cast_hash,
+                    requested_at,
+                    actions,
+                    attester,
+                };
+                let Some(signature) = sp_io::crypto::ed25519_sign(
+                    KEY_TYPE,
+                    &attester,
+                    &Self::engagement_verdict_payload(&verdict),
+                ) else {
+                    log::warn!(target: LOG_TARGET, "attester key left the keystore");
+                    return;
+                };
+                let call = Call::submit_engagement_verdict { verdict, signature };
+                if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+                    .is_err()
+                {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "submitting engagement verdict for FID {:?} failed",
+                        fid,
+                    );
+                }
+            }
+        }
+
+        /// Drop the link between `account` and `fid`, and the FID from the account's profile.
+        fn unlink(account: &T::AccountId, fid: Fid) {
+            FidOf::<T>::remove(account);
+            AccountOfFid::<T>::remove(fid);
+            ArtistProfiles::<T>::mutate(account, |profile| {
+                if let Some(profile) = profile {
+                    if profile.fid.take().is_some() {
+                        FidToArtist::<T>::remove(fid);
+                    }
+                }
+            });
+            Self::deposit_event(Event::FidUnlinked(fid, account.clone()));
+        }
+
+        /// Remove the profile of `who` along with its FID claim, if any.
+        fn remove_profile(who: &T::AccountId) {
+            if let Some(profile) = ArtistProfiles::<T>::take(who) {
+                if let Some(fid) = profile.fid {
+                    FidToArtist::<T>::remove(fid);
+                }
+            }
+        }
+
+        /// Ensure the item is not locked in a running auction.
+        pub(crate) fn ensure_not_in_auction(
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> DispatchResult {
+            ensure!(
+                !Auctions::<T>::contains_key(collection_id, item_id),
+                Error::<T>::NftInAuction
+            );
+            Ok(())
+        }
+
+        /// Remove the offer of `buyer` on an item, keeping the item's offer count in step.
+        fn take_offer(
+            collection_id: CollectionId,
+            item_id: ItemId,
+            buyer: &T::AccountId,
+        ) -> Result<OfferOf<T>, DispatchError> {
+            let offer = Offers::<T>::take((collection_id, item_id, buyer))
+                .ok_or(Error::<T>::OfferNotFound)?;
+            OfferCount::<T>::mutate(collection_id, item_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+            Ok(offer)
+        }
+
+        /// Hand an item to a new owner, cancelling any listing. `sale_price` is recorded as the
+        /// item's price when it changed hands through a sale.
+        pub(crate) fn transfer_ownership(
+            collection_id: CollectionId,
+            item_id: ItemId,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            sale_price: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
             Nfts::<T>::try_mutate(collection_id, item_id, |nft_option| -> DispatchResult {
                 let nft = nft_option.as_mut().ok_or(Error::<T>::NFTNotFound)?;
-                nft.owner = buyer.clone();
+                nft.owner = to.clone();
                 nft.is_sold = true;
+                if let Some(price) = sale_price {
+                    nft.price = price;
+                }
                 Ok(())
             })?;
+            Self::move_owner_index(collection_id, item_id, from, to);
+            Listings::<T>::remove(collection_id, item_id);
 
-            Self::deposit_event(Event::NFTTransferred(collection_id, item_id, seller, buyer));
+            // The storage deposit follows the item to its new owner
+            let deposit = ItemDeposits::<T>::get(collection_id, item_id);
+            Self::release_deposit(HoldReason::ItemDeposit, from, deposit);
+            T::Currency::hold(&HoldReason::ItemDeposit.into(), to, deposit)?;
+            Ok(())
+        }
+
+        /// Deposit for an entry with `base` plus `bytes` of metadata.
+        fn deposit_for(base: BalanceOf<T>, bytes: usize) -> BalanceOf<T> {
+            T::DepositPerByte::get()
+                .saturating_mul((bytes as u32).into())
+                .saturating_add(base)
+        }
+
+        /// Hold or release the difference when a deposit of `who` changes from `old` to `new`.
+        fn adjust_deposit(
+            reason: HoldReason,
+            who: &T::AccountId,
+            old: BalanceOf<T>,
+            new: BalanceOf<T>,
+        ) -> DispatchResult {
+            if new > old {
+                T::Currency::hold(&reason.into(), who, new - old)?;
+            } else {
+                Self::release_deposit(reason, who, old - new);
+            }
+            Ok(())
+        }
+
+        /// Release a storage deposit. Deposits are only ever held by this pallet, so a shortfall
+        /// is not expected and is released on a best-effort basis.
+        fn release_deposit(reason: HoldReason, who: &T::AccountId, amount: BalanceOf<T>) {
+            let _ = T::Currency::release(&reason.into(), who, amount, Precision::BestEffort);
+        }
+
+        /// Close an auction that reached its end block.
+        ///
+        /// The highest bid pays for the item like a regular sale. If that fails, the bid is
+        /// released and the item stays with the seller.
+        fn settle_auction(collection_id: CollectionId, item_id: ItemId) {
+            let Some(auction) = Auctions::<T>::take(collection_id, item_id) else {
+                return;
+            };
+            let Some((winner, amount)) = auction.highest_bid else {
+                Self::deposit_event(Event::AuctionCancelled(collection_id, item_id));
+                return;
+            };
+
+            let result = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+                T::Currency::release(
+                    &HoldReason::AuctionBid.into(),
+                    &winner,
+                    amount,
+                    Precision::Exact,
+                )?;
+                Self::pay_for_item(&winner, &auction.seller, collection_id, item_id, amount)?;
+                Self::transfer_ownership(
+                    collection_id,
+                    item_id,
+                    &auction.seller,
+                    &winner,
+                    Some(amount),
+                )
+            });
+
+            match result {
+                Ok(()) => {
+                    Self::deposit_event(Event::NFTTransferred(
+                        collection_id,
+                        item_id,
+                        auction.seller,
+                        winner.clone(),
+                    ));
+                    Self::deposit_event(Event::AuctionSettled(
+                        collection_id,
+                        item_id,
+                        winner,
+                        amount,
+                    ));
+                }
+                Err(error) => {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "settling auction {:?}/{:?} failed: {:?}",
+                        collection_id,
+                        item_id,
+                        error,
+                    );
+                    let _ = T::Currency::release(
+                        &HoldReason::AuctionBid.into(),
+                        &winner,
+                        amount,
+                        Precision::BestEffort,
+                    );
+                    Self::deposit_event(Event::AuctionCancelled(collection_id, item_id));
+                }
+            }
+        }
+
+        /// The account holding registration and marketplace fees.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Pay `price` from `buyer` for an item, splitting off the marketplace fee and the
+        /// collection royalty before the remainder goes to `seller`.
+        ///
+        /// A royalty the recipient cannot take, such as one below the existential deposit for an
+        /// account that does not exist yet, goes to `seller` instead of failing the sale.
+        fn pay_for_item(
+            buyer: &T::AccountId,
+            seller: &T::AccountId,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let mut remainder = price;
+
+            let fee = T::MarketplaceFee::get().mul_floor(price);
+            if !fee.is_zero() {
+                T::Currency::transfer(buyer, &Self::account_id(), fee, Preservation::Preserve)?;
+                remainder = remainder.saturating_sub(fee);
+                Self::deposit_event(Event::MarketplaceFeePaid(collection_id, item_id, fee));
+            }
+
+            if let Some(collection) = Collections::<T>::get(collection_id) {
+                let royalty = collection.royalty.mul_floor(price);
+                let receivable = T::Currency::can_deposit(
+                    &collection.royalty_recipient,
+                    royalty,
+                    Provenance::Extant,
+                ) == DepositConsequence::Success;
+                if !royalty.is_zero() && receivable {
+                    T::Currency::transfer(
+                        buyer,
+                        &collection.royalty_recipient,
+                        royalty,
+                        Preservation::Preserve,
+                    )?;
+                    remainder = remainder.saturating_sub(royalty);
+                    Self::deposit_event(Event::RoyaltyPaid(
+                        collection_id,
+                        item_id,
+                        collection.royalty_recipient,
+                        royalty,
+                    ));
+                }
+            }
+
+            T::Currency::transfer(buyer, seller, remainder, Preservation::Preserve)?;
+            Ok(())
+        }
+
+        /// Record the asking price of an item, keeping `NFT::price` in step with the listing.
+        fn set_listing_price(collection_id: CollectionId, item_id: ItemId, price: BalanceOf<T>) {
+            Listings::<T>::insert(collection_id, item_id, price);
+            Nfts::<T>::mutate(collection_id, item_id, |nft| {
+                if let Some(nft) = nft {
+                    nft.price = price;
+                }
+            });
+        }
+
+        /// Move the `OwnedNfts` entry of an item from one owner to another, keeping
+        /// `ForeignItems` in step.
+        fn move_owner_index(
+            collection_id: CollectionId,
+            item_id: ItemId,
+            from: &T::AccountId,
+            to: &T::AccountId,
+        ) {
+            OwnedNfts::<T>::remove(from, (collection_id, item_id));
+            OwnedNfts::<T>::insert(to, (collection_id, item_id), ());
+
+            let Some(collection) = Collections::<T>::get(collection_id) else {
+                return;
+            };
+            if *from == collection.creator && *to != collection.creator {
+                ForeignItems::<T>::mutate(collection_id, |count| *count = count.saturating_add(1));
+            } else if *from != collection.creator && *to == collection.creator {
+                ForeignItems::<T>::mutate(collection_id, |count| *count = count.saturating_sub(1));
+            }
+        }
+
+        /// Collections starting after `cursor`, returning at most `limit` entries.
+        pub fn list_collections(
+            cursor: Option<CollectionId>,
+            limit: u32,
+        ) -> Vec<(CollectionId, Collection<T::AccountId>)> {
+            let collections = match cursor {
+                Some(collection_id) => {
+                    Collections::<T>::iter_from(Collections::<T>::hashed_key_for(collection_id))
+                }
+                None => Collections::<T>::iter(),
+            };
+            collections
+                .take(limit.min(MAX_PAGE_SIZE) as usize)
+                .collect()
+        }
+
+        /// NFTs owned by `owner`, starting after `cursor` and returning at most `limit` entries.
+        ///
+        /// Entries come back in storage order; pass the last returned key as the next `cursor`.
+        pub fn nfts_of_owner(
+            owner: T::AccountId,
+            cursor: Option<(CollectionId, ItemId)>,
+            limit: u32,
+        ) -> Vec<(CollectionId, ItemId, NftOf<T>)> {
+            let keys = match cursor {
+                Some(key) => OwnedNfts::<T>::iter_key_prefix_from(
+                    &owner,
+                    OwnedNfts::<T>::hashed_key_for(&owner, key),
+                ),
+                None => OwnedNfts::<T>::iter_key_prefix(&owner),
+            };
+            keys.filter_map(|(collection_id, item_id)| {
+                Nfts::<T>::get(collection_id, item_id).map(|nft| (collection_id, item_id, nft))
+            })
+            .take(limit.min(MAX_PAGE_SIZE) as usize)
+            .collect()
+        }
+
+        /// Items of `collection_id`, starting after `cursor` and returning at most `limit`
+        /// entries.
+        pub fn collection_items(
+            collection_id: CollectionId,
+            cursor: Option<ItemId>,
+            limit: u32,
+        ) -> Vec<(ItemId, NftOf<T>)> {
+            let items = match cursor {
+                Some(item_id) => Nfts::<T>::iter_prefix_from(
+                    collection_id,
+                    Nfts::<T>::hashed_key_for(collection_id, item_id),
+                ),
+                None => Nfts::<T>::iter_prefix(collection_id),
+            };
+            items.take(limit.min(MAX_PAGE_SIZE) as usize).collect()
+        }
+
+        /// A single NFT, if it exists.
+        pub fn nft_details(collection_id: CollectionId, item_id: ItemId) -> Option<NftOf<T>> {
+            Nfts::<T>::get(collection_id, item_id)
+        }
+
+        /// The asking price of an item, if it is currently listed.
+        pub fn listing_price(collection_id: CollectionId, item_id: ItemId) -> Option<BalanceOf<T>> {
+            Listings::<T>::get(collection_id, item_id)
+        }
+
+        /// The artist whose profile claims `fid`, with that profile.
+        pub fn artist_by_fid(fid: Fid) -> Option<(T::AccountId, ArtistProfile)> {
+            let artist = FidToArtist::<T>::get(fid)?;
+            let profile = ArtistProfiles::<T>::get(&artist)?;
+            Some((artist, profile))
+        }
+    }
+
+    #[cfg(any(feature = "try-runtime", test))]
+    impl<T: Config> Pallet<T> {
+        /// Check the invariants that tie the storage items of the pallet together.
+        ///
+        /// Run by the `try_state` hook and after every unit test.
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::check_collections()?;
+            Self::check_owner_index()?;
+            Self::check_markets()?;
+            Self::check_fid_links()?;
+            Self::check_engagement_checks()
+        }
+
+        /// `Collection::items` counts the items stored under the collection within its
+        /// `max_supply`, every item id is below `NextItemId`, and creators are registered artists
+        /// with the right collection count.
+        fn check_collections() -> Result<(), sp_runtime::TryRuntimeError> {
+            use alloc::collections::BTreeMap;
+
+            let mut created = BTreeMap::<T::AccountId, u32>::new();
+            for (collection_id, collection) in Collections::<T>::iter() {
+                ensure!(
+                    Artists::<T>::contains_key(&collection.creator),
+                    "collection creator is not a registered artist"
+                );
+                *created.entry(collection.creator.clone()).or_default() += 1;
+
+                let next_item_id = NextItemId::<T>::get(collection_id);
+                let mut items = 0u32;
+                for item_id in Nfts::<T>::iter_key_prefix(collection_id) {
+                    ensure!(item_id < next_item_id, "item id is not below NextItemId");
+                    items += 1;
+                }
+                ensure!(
+                    items == collection.items,
+                    "item count does not match the items of the collection"
+                );
+                ensure!(
+                    collection.max_supply.is_none_or(|max| items <= max),
+                    "collection holds more items than its max supply"
+                );
+                let foreign = Nfts::<T>::iter_prefix_values(collection_id)
+                    .filter(|nft| nft.owner != collection.creator)
+                    .count() as u32;
+                ensure!(
+                    ForeignItems::<T>::get(collection_id) == foreign,
+                    "ForeignItems does not match the items owned by others"
+                );
+            }
+            ensure!(
+                Nfts::<T>::iter_keys()
+                    .all(|(collection_id, _)| Collections::<T>::contains_key(collection_id)),
+                "item of a missing collection"
+            );
+            ensure!(
+                ForeignItems::<T>::iter_keys().all(Collections::<T>::contains_key),
+                "ForeignItems of a missing collection"
+            );
+
+            for (artist, count) in ArtistCollectionCount::<T>::iter() {
+                ensure!(
+                    created.get(&artist).copied().unwrap_or_default() == count,
+                    "ArtistCollectionCount does not match the collections of the artist"
+                );
+            }
+            for (artist, count) in created {
+                ensure!(
+                    ArtistCollectionCount::<T>::get(&artist) == count,
+                    "ArtistCollectionCount does not match the collections of the artist"
+                );
+            }
+            Ok(())
+        }
+
+        /// `OwnedNfts` has exactly one entry per item, under its owner.
+        fn check_owner_index() -> Result<(), sp_runtime::TryRuntimeError> {
+            for (collection_id, item_id, nft) in Nfts::<T>::iter() {
+                ensure!(
+                    OwnedNfts::<T>::contains_key(&nft.owner, (collection_id, item_id)),
+                    "item missing from the index of its owner"
+                );
+            }
+            for (owner, (collection_id, item_id), ()) in OwnedNfts::<T>::iter() {
+                ensure!(
+                    Nfts::<T>::get(collection_id, item_id).is_some_and(|nft| nft.owner == owner),
+                    "owner index entry for an item the account does not own"
+                );
+            }
+            Ok(())
+        }
+
+        /// Listings, auctions and offers are for existing items, listings and auctions never
+        /// both at once, every auction is scheduled to be settled at its end, and `OfferCount`
+        /// counts the offers of each item.
+        fn check_markets() -> Result<(), sp_runtime::TryRuntimeError> {
+            for (collection_id, item_id) in Listings::<T>::iter_keys() {
+                ensure!(
+                    Nfts::<T>::contains_key(collection_id, item_id),
+                    "listing of a missing item"
+                );
+                ensure!(
+                    !Auctions::<T>::contains_key(collection_id, item_id),
+                    "item both listed and auctioned"
+                );
+            }
+            for (collection_id, item_id, auction) in Auctions::<T>::iter() {
+                ensure!(
+                    Nfts::<T>::get(collection_id, item_id)
+                        .is_some_and(|nft| nft.owner == auction.seller),
+                    "auction of an item the seller does not own"
+                );
+                ensure!(
+                    AuctionsEndingAt::<T>::get(auction.end).contains(&(collection_id, item_id)),
+                    "auction not scheduled at its end"
+                );
+            }
+
+            let mut offers = alloc::collections::BTreeMap::<(CollectionId, ItemId), u32>::new();
+            for (collection_id, item_id, _) in Offers::<T>::iter_keys() {
+                ensure!(
+                    Nfts::<T>::contains_key(collection_id, item_id),
+                    "offer on a missing item"
+                );
+                *offers.entry((collection_id, item_id)).or_default() += 1;
+            }
+            for (collection_id, item_id, count) in OfferCount::<T>::iter() {
+                ensure!(
+                    offers.remove(&(collection_id, item_id)).unwrap_or_default() == count,
+                    "OfferCount does not match the offers of the item"
+                );
+            }
+            ensure!(offers.is_empty(), "offers missing from OfferCount");
+            Ok(())
+        }
+
+        /// `FidOf` and `AccountOfFid` mirror each other, and `FidToArtist` indexes the FIDs
+        /// claimed by profiles, which must be linked to the artist.
+        fn check_fid_links() -> Result<(), sp_runtime::TryRuntimeError> {
+            for (account, fid) in FidOf::<T>::iter() {
+                ensure!(
+                    AccountOfFid::<T>::get(fid) == Some(account),
+                    "FidOf without a matching AccountOfFid"
+                );
+            }
+            for (fid, account) in AccountOfFid::<T>::iter() {
+                ensure!(
+                    FidOf::<T>::get(&account) == Some(fid),
+                    "AccountOfFid without a matching FidOf"
+                );
+            }
+            for (artist, profile) in ArtistProfiles::<T>::iter() {
+                if let Some(fid) = profile.fid {
+                    ensure!(
+                        FidToArtist::<T>::get(fid) == Some(artist.clone()),
+                        "profile FID missing from FidToArtist"
+                    );
+                    ensure!(
+                        FidOf::<T>::get(&artist) == Some(fid),
+                        "profile claims an FID the artist has not linked"
+                    );
+                }
+            }
+            for (fid, artist) in FidToArtist::<T>::iter() {
+                ensure!(
+                    ArtistProfiles::<T>::get(&artist)
+                        .is_some_and(|profile| profile.fid == Some(fid)),
+                    "FidToArtist entry without a profile claiming the FID"
+                );
+            }
+            Ok(())
+        }
+
+        /// `PendingEngagementCheckCount` counts the pending checks of each requester.
+        fn check_engagement_checks() -> Result<(), sp_runtime::TryRuntimeError> {
+            use alloc::collections::BTreeMap;
+
+            let mut pending = BTreeMap::<T::AccountId, u32>::new();
+            for check in PendingEngagementChecks::<T>::iter_values() {
+                *pending.entry(check.requester).or_default() += 1;
+            }
+            for (requester, count) in PendingEngagementCheckCount::<T>::iter() {
+                ensure!(
+                    pending.remove(&requester) == Some(count),
+                    "PendingEngagementCheckCount does not match the checks of the account"
+                );
+            }
+            ensure!(
+                pending.is_empty(),
+                "pending engagement check missing from PendingEngagementCheckCount"
+            );
             Ok(())
         }
     }
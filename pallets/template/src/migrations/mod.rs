@@ -0,0 +1,9 @@
+//! Storage migrations of the NFT marketplace pallet.
+//!
+//! Each `vN` module moves the storage from version `N - 1` to `N` and is wrapped in a
+//! [`VersionedMigration`](frame_support::migrations::VersionedMigration), so it only runs on a
+//! chain at the version it expects. Runtimes list them in order in their `Executive`
+//! migrations.
+
+pub mod v1;
+pub mod v2;
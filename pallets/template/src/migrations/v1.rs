@@ -0,0 +1,175 @@
+//! Version 1: the marketplace layout with artist approval, royalties, listings and the owner
+//! index.
+//!
+//! Version 0 chains stored artists as a `bool` and collections without royalty or frozen
+//! fields, kept no owner index or collection count, and sold every unsold item at its price.
+//! Registered artists become verified, so they keep creating collections, and every unsold item
+//! is listed at its price. Collections are written in the version 1 layout kept in
+//! [`super::v2::v1`].
+//!
+//! Migrated artists never paid [`Config::ArtistBond`], so it is held from them now. Artists who
+//! cannot cover it stay verified without a bond rather than losing their collections; nothing
+//! relies on the hold, and deregistering releases whatever is held.
+//!
+//! The migration runs in a single block, so `pre_upgrade` checks that the chain holds at most
+//! [`MAX_ENTRIES`] artists, collections and items, which keeps its proof well below the parachain
+//! limit. Larger chains need a multi-block migration instead.
+
+use super::v2::v1 as next;
+use crate::{
+    ArtistCollectionCount, ArtistStatus, Artists, CollectionId, Config, HoldReason, ItemId,
+    Listings, Nfts, OwnedNfts, Pallet, LOG_TARGET,
+};
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::*,
+    sp_runtime::Perbill,
+    traits::{fungible::MutateHold, UncheckedOnRuntimeUpgrade},
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Most artists, collections and items together that a chain may hold at version 0.
+pub const MAX_ENTRIES: u32 = 1_000;
+
+/// Storage items as laid out at version 0.
+pub mod v0 {
+    use super::*;
+
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct Collection<AccountId> {
+        pub creator: AccountId,
+        pub metadata: BoundedVec<u8, ConstU32<256>>,
+        pub nfts: BoundedVec<ItemId, ConstU32<256>>,
+    }
+
+    #[frame_support::storage_alias]
+    pub type Collections<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        CollectionId,
+        Collection<<T as frame_system::Config>::AccountId>,
+        OptionQuery,
+    >;
+
+    #[frame_support::storage_alias]
+    pub type Artists<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        bool,
+        ValueQuery,
+    >;
+}
+
+/// Moves the storage from version 0 to 1 without checking the storage version; use
+/// [`MigrateV0ToV1`] instead.
+pub struct VersionUncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+
+        Artists::<T>::translate::<bool, _>(|artist, registered| {
+            reads += 1;
+            writes += 1;
+            if !registered {
+                return None;
+            }
+            reads += 2;
+            writes += 2;
+            let bond = HoldReason::ArtistBond.into();
+            if let Err(err) = T::Currency::hold(&bond, &artist, T::ArtistBond::get()) {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "artist {:?} cannot cover the artist bond: {:?}",
+                    artist,
+                    err,
+                );
+            }
+            Some(ArtistStatus::Verified)
+        });
+
+        next::Collections::<T>::translate::<v0::Collection<T::AccountId>, _>(|_, old| {
+            reads += 1;
+            writes += 2;
+            ArtistCollectionCount::<T>::mutate(&old.creator, |count| {
+                *count = count.saturating_add(1)
+            });
+            Some(next::Collection {
+                royalty: Perbill::zero(),
+                royalty_recipient: old.creator.clone(),
+                creator: old.creator,
+                metadata: old.metadata,
+                nfts: old.nfts,
+                is_frozen: false,
+            })
+        });
+
+        for (collection_id, item_id, nft) in Nfts::<T>::iter() {
+            reads += 1;
+            writes += 1;
+            OwnedNfts::<T>::insert(&nft.owner, (collection_id, item_id), ());
+            if !nft.is_sold {
+                writes += 1;
+                Listings::<T>::insert(collection_id, item_id, nft.price);
+            }
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let entries = v0::Artists::<T>::iter_keys().count()
+            + v0::Collections::<T>::iter_keys().count()
+            + Nfts::<T>::iter_keys().count();
+        ensure!(
+            entries as u32 <= MAX_ENTRIES,
+            "too many entries to migrate in one block"
+        );
+
+        let artists = v0::Artists::<T>::iter_values()
+            .filter(|registered| *registered)
+            .count() as u32;
+        let collections = v0::Collections::<T>::iter_values().count() as u32;
+        let items = Nfts::<T>::iter_values().count() as u32;
+        let unsold = Nfts::<T>::iter_values().filter(|nft| !nft.is_sold).count() as u32;
+        Ok((artists, collections, items, unsold).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let (artists, collections, items, unsold) = <(u32, u32, u32, u32)>::decode(&mut &state[..])
+            .map_err(|_| "the pre-upgrade state does not decode")?;
+
+        ensure!(
+            Artists::<T>::iter_values().all(|status| status == ArtistStatus::Verified)
+                && Artists::<T>::iter_values().count() as u32 == artists,
+            "a registered artist was lost or not verified"
+        );
+        ensure!(
+            next::Collections::<T>::iter_values().count() as u32 == collections,
+            "a collection was lost or does not decode"
+        );
+        ensure!(
+            OwnedNfts::<T>::iter_keys().count() as u32 == items,
+            "an item is missing from the owner index"
+        );
+        ensure!(
+            Listings::<T>::iter_keys().count() as u32 == unsold,
+            "an unsold item was not listed"
+        );
+        Ok(())
+    }
+}
+
+/// Moves the storage from version 0 to 1.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    VersionUncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -0,0 +1,134 @@
+//! Version 2: collections count their items instead of listing them.
+//!
+//! Version 1 collections kept the ids of their items in a vector capped at 256 entries. The
+//! items of a collection are already the `Nfts` entries under its id, so the vector becomes a
+//! counter, and collections get an optional `max_supply`, left unset for existing ones.
+//!
+//! `ForeignItems` is filled in from the owners of the items; the 256 item cap of version 1
+//! bounds the items read per collection. Offers on items burned before version 2 are dropped
+//! along with their `OfferCount` and their funds released.
+
+use crate::{
+    Collection, CollectionId, Collections, Config, ForeignItems, HoldReason, ItemId, Nfts,
+    OfferCount, Offers, Pallet,
+};
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::*,
+    sp_runtime::Perbill,
+    traits::{fungible::MutateHold, tokens::Precision, UncheckedOnRuntimeUpgrade},
+};
+use sp_std::vec::Vec;
+
+/// Storage items as laid out at version 1.
+pub mod v1 {
+    use super::*;
+
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct Collection<AccountId> {
+        pub creator: AccountId,
+        pub metadata: BoundedVec<u8, ConstU32<256>>,
+        pub nfts: BoundedVec<ItemId, ConstU32<256>>,
+        pub royalty: Perbill,
+        pub royalty_recipient: AccountId,
+        pub is_frozen: bool,
+    }
+
+    #[frame_support::storage_alias]
+    pub type Collections<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        CollectionId,
+        Collection<<T as frame_system::Config>::AccountId>,
+        OptionQuery,
+    >;
+}
+
+/// Moves the storage from version 1 to 2 without checking the storage version; use
+/// [`MigrateV1ToV2`] instead.
+pub struct VersionUncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV1ToV2<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut translated = 0u64;
+        let mut items = 0u64;
+
+        Collections::<T>::translate::<v1::Collection<T::AccountId>, _>(|collection_id, old| {
+            translated += 1;
+            items += old.nfts.len() as u64;
+            let foreign = Nfts::<T>::iter_prefix_values(collection_id)
+                .filter(|nft| nft.owner != old.creator)
+                .count() as u32;
+            if foreign > 0 {
+                ForeignItems::<T>::insert(collection_id, foreign);
+            }
+            Some(Collection {
+                creator: old.creator,
+                metadata: old.metadata,
+                items: old.nfts.len() as u32,
+                max_supply: None,
+                royalty: old.royalty,
+                royalty_recipient: old.royalty_recipient,
+                is_frozen: old.is_frozen,
+            })
+        });
+
+        let mut offers = 0u64;
+        let mut orphaned = Vec::new();
+        for (collection_id, item_id, buyer) in Offers::<T>::iter_keys() {
+            offers += 1;
+            if !Nfts::<T>::contains_key(collection_id, item_id) {
+                orphaned.push((collection_id, item_id, buyer));
+            }
+        }
+        for key in orphaned {
+            if let Some(offer) = Offers::<T>::take(&key) {
+                let _ = T::Currency::release(
+                    &HoldReason::Offer.into(),
+                    &key.2,
+                    offer.amount,
+                    Precision::BestEffort,
+                );
+            }
+            OfferCount::<T>::remove(key.0, key.1);
+        }
+
+        T::DbWeight::get()
+            .reads_writes(translated + items + offers * 2, translated * 2 + offers * 2)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let collections = v1::Collections::<T>::iter_values().count() as u32;
+        let items = Nfts::<T>::iter_keys().count() as u32;
+        Ok((collections, items).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let (collections, items) = <(u32, u32)>::decode(&mut &state[..])
+            .map_err(|_| "the pre-upgrade state does not decode")?;
+
+        ensure!(
+            Collections::<T>::iter_values().count() as u32 == collections,
+            "a collection was lost or does not decode"
+        );
+        ensure!(
+            Collections::<T>::iter_values()
+                .map(|collection| collection.items)
+                .sum::<u32>()
+                == items,
+            "the item counts do not add up to the stored items"
+        );
+        Pallet::<T>::do_try_state()
+    }
+}
+
+/// Moves the storage from version 1 to 2.
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    VersionUncheckedMigrateV1ToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -1,6 +1,24 @@
-use frame_support::{derive_impl, weights::constants::RocksDbWeight};
-use frame_system::{mocking::MockBlock, GenesisConfig};
-use sp_runtime::{traits::ConstU64, BuildStorage};
+use frame_support::{
+	derive_impl, parameter_types, traits::IsInVec, weights::constants::RocksDbWeight, PalletId,
+};
+use frame_system::{mocking::MockBlock, offchain::SendTransactionTypes, EnsureRoot};
+use sp_core::ed25519;
+use sp_runtime::{
+	testing::TestXt,
+	traits::{ConstU32, ConstU64},
+	BuildStorage, Perbill,
+};
+use std::ops::{Deref, DerefMut};
+
+pub type AccountId = u64;
+pub type Balance = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+/// Balance every test account starts with.
+pub const INITIAL_BALANCE: Balance = 1_000_000;
 
 // Configure a mock runtime to test the pallet.
 #[frame_support::runtime]
@@ -22,6 +40,8 @@ mod test_runtime {
 	#[runtime::pallet_index(0)]
 	pub type System = frame_system;
 	#[runtime::pallet_index(1)]
+	pub type Balances = pallet_balances;
+	#[runtime::pallet_index(2)]
 	pub type TemplateModule = crate;
 }
 
@@ -31,14 +51,171 @@ impl frame_system::Config for Test {
 	type Block = MockBlock<Test>;
 	type BlockHashCount = ConstU64<250>;
 	type DbWeight = RocksDbWeight;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub static ExistentialDeposit: Balance = 1;
+	pub const TemplatePalletId: PalletId = PalletId(*b"template");
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(25);
+	pub static MarketplaceFee: Perbill = Perbill::zero();
+	pub static ArtistBond: Balance = 0;
+	pub static CollectionDeposit: Balance = 0;
+	pub static ItemDeposit: Balance = 0;
+	pub static DepositPerByte: Balance = 0;
+	pub static Attesters: Vec<ed25519::Public> = vec![];
+	pub static FidSigners: Vec<(crate::Fid, ed25519::Public)> = vec![];
+	pub static OracleEngagements: Vec<(crate::Fid, crate::CastHash, crate::EngagementActions)> = vec![];
+}
+
+/// Oracle reporting the reactions in [`OracleEngagements`].
+pub struct MockOracle;
+
+impl crate::SocialAttestations for MockOracle {
+	fn engagement(fid: crate::Fid, cast_hash: &crate::CastHash) -> crate::EngagementActions {
+		OracleEngagements::get()
+			.into_iter()
+			.find(|(other_fid, other_hash, _)| *other_fid == fid && other_hash == cast_hash)
+			.map(|(_, _, actions)| actions)
+			.unwrap_or_default()
+	}
+
+	fn follows(_: crate::Fid, _: crate::Fid) -> bool {
+		false
+	}
+
+	fn follower_count(_: crate::Fid) -> Option<u64> {
+		None
+	}
 }
 
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type Currency = Balances;
+	type PalletId = TemplatePalletId;
+	type MaxRoyalty = MaxRoyalty;
+	type MarketplaceFee = MarketplaceFee;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MinAuctionDuration = ConstU64<10>;
+	type MaxAuctionDuration = ConstU64<1000>;
+	type AntiSnipingPeriod = ConstU64<5>;
+	type MaxAuctionsPerBlock = ConstU32<10>;
+	type MaxOffersPerItem = ConstU32<3>;
+	type MinOfferAmount = ConstU64<10>;
+	type MaxOfferDuration = ConstU64<100>;
+	type MaxBatchSize = ConstU32<5>;
+	type RemoveItemsLimit = ConstU32<2>;
+	type ArtistRegistrationFee = ConstU64<100>;
+	type ArtistBond = ArtistBond;
+	type ArtistAdminOrigin = EnsureRoot<AccountId>;
+	type FidSigners = IsInVec<FidSigners>;
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type DepositPerByte = DepositPerByte;
+	type TrustedAttesters = IsInVec<Attesters>;
+	type MaxEngagementChecksPerBlock = ConstU32<2>;
+	type MaxPendingEngagementChecks = ConstU32<2>;
+	type EngagementCheckTimeout = ConstU64<20>;
+	type UnsignedPriority = ConstU64<100>;
+	type SocialAttestations = MockOracle;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+/// Trusts attesters by adding them to [`Attesters`] and signers to [`FidSigners`].
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper for MockBenchmarkHelper {
+	fn trust_attester(attester: ed25519::Public) {
+		Attesters::mutate(|attesters| attesters.push(attester));
+	}
+
+	fn add_fid_signer(fid: crate::Fid, signer: ed25519::Public) {
+		FidSigners::mutate(|signers| signers.push((fid, signer)));
+	}
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<C> SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+/// Advance to block `n`, running the pallet's `on_initialize` on the way.
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		<TemplateModule as frame_support::traits::Hooks<u64>>::on_initialize(next);
+	}
+}
+
+/// Test externalities that check the invariants of the pallet after every `execute_with`.
+pub struct TestExt(sp_io::TestExternalities);
+
+impl TestExt {
+	pub fn execute_with<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+		self.0.execute_with(|| {
+			let result = execute();
+			TemplateModule::do_try_state().expect("pallet invariants hold");
+			result
+		})
+	}
+}
+
+impl Deref for TestExt {
+	type Target = sp_io::TestExternalities;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl DerefMut for TestExt {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
 }
 
 // Build genesis storage according to the mock runtime.
-pub fn new_test_ext() -> sp_io::TestExternalities {
-	GenesisConfig::<Test>::default().build_storage().unwrap().into()
+pub fn new_test_ext() -> TestExt {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(BOB, INITIAL_BALANCE),
+			(CHARLIE, INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	TestExt(ext)
+}
+
+/// Like [`new_test_ext`], with a keystore for the benchmarks to sign with.
+#[cfg(feature = "runtime-benchmarks")]
+pub fn new_bench_ext() -> TestExt {
+	let mut ext = new_test_ext();
+	ext.register_extension(sp_keystore::KeystoreExt::new(std::sync::Arc::new(
+		sp_keystore::testing::MemoryKeystore::new(),
+	)));
+	ext
 }
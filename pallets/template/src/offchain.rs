@@ -0,0 +1,121 @@
+//! Farcaster Hub client used by the offchain worker to check cast engagement.
+//!
+//! Each reaction is looked up with the `reactionById` endpoint of a Hub-compatible HTTP API. The
+//! base URL is taken from the node's persistent offchain storage under [`HUB_URL_KEY`], so
+//! operators can point the worker at their own Hub through the `offchain_localStorageSet` RPC.
+
+use crate::{CastHash, EngagementActions, Fid, LOG_TARGET};
+use alloc::{format, string::String, vec::Vec};
+use serde::Deserialize;
+use sp_core::{
+    hexdisplay::HexDisplay,
+    offchain::{Duration, StorageKind},
+};
+use sp_runtime::offchain::http;
+
+/// Offchain storage key holding the Hub HTTP API base URL, e.g. `http://127.0.0.1:2281`.
+pub const HUB_URL_KEY: &[u8] = b"nft-marketplace::hub-url";
+/// Hub used when [`HUB_URL_KEY`] is not set: a Hubble node on the same host.
+pub const DEFAULT_HUB_URL: &str = "http://127.0.0.1:2281";
+
+const HTTP_TIMEOUT_MS: u64 = 5_000;
+
+#[derive(Deserialize)]
+struct Message {
+    data: MessageData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessageData {
+    reaction_body: Option<ReactionBody>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReactionBody {
+    /// Reactions may target a URL instead of a cast.
+    target_cast_id: Option<CastId>,
+}
+
+#[derive(Deserialize)]
+struct CastId {
+    hash: String,
+}
+
+/// Body of a failed Hub request.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HubError {
+    err_code: String,
+}
+
+/// Base URL of the Hub to query.
+pub fn hub_url() -> String {
+    sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, HUB_URL_KEY)
+        .and_then(|url| String::from_utf8(url).ok())
+        .map(|url| url.trim_end_matches('/').into())
+        .unwrap_or_else(|| DEFAULT_HUB_URL.into())
+}
+
+/// Reactions of `fid` to the cast of `cast_fid` with `cast_hash`, as known to the Hub at `hub`.
+pub fn fetch_engagement(
+    hub: &str,
+    fid: Fid,
+    cast_fid: Fid,
+    cast_hash: &CastHash,
+) -> Result<EngagementActions, http::Error> {
+    let target = format!("0x{}", HexDisplay::from(cast_hash));
+    Ok(EngagementActions {
+        like: has_reacted(hub, fid, cast_fid, &target, "REACTION_TYPE_LIKE")?,
+        recast: has_reacted(hub, fid, cast_fid, &target, "REACTION_TYPE_RECAST")?,
+    })
+}
+
+/// Whether the Hub holds a reaction of `reaction_type` by `fid` to the cast `target`.
+fn has_reacted(
+    hub: &str,
+    fid: Fid,
+    cast_fid: Fid,
+    target: &str,
+    reaction_type: &str,
+) -> Result<bool, http::Error> {
+    let url = format!(
+        "{hub}/v1/reactionById?fid={fid}&reaction_type={reaction_type}\
+         &target_fid={cast_fid}&target_hash={target}"
+    );
+    let Some(body) = get(&url)? else {
+        return Ok(false);
+    };
+    let message: Message = serde_json::from_slice(&body).map_err(|_| {
+        log::warn!(target: LOG_TARGET, "unexpected Hub response from {}", url);
+        http::Error::Unknown
+    })?;
+    Ok(message
+        .data
+        .reaction_body
+        .and_then(|body| body.target_cast_id)
+        .is_some_and(|cast| cast.hash.eq_ignore_ascii_case(target)))
+}
+
+/// Body of the Hub response to `url`, or `None` if the Hub has no such message.
+fn get(url: &str) -> Result<Option<Vec<u8>>, http::Error> {
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+    let pending = http::Request::get(url)
+        .deadline(deadline)
+        .send()
+        .map_err(|_| http::Error::IoError)?;
+    let response = pending
+        .try_wait(deadline)
+        .map_err(|_| http::Error::DeadlineReached)??;
+    let code = response.code;
+    let body: Vec<u8> = response.body().collect();
+    if serde_json::from_slice::<HubError>(&body).is_ok_and(|err| err.err_code == "not_found") {
+        return Ok(None);
+    }
+    if code != 200 {
+        log::warn!(target: LOG_TARGET, "Hub returned {} for {}", code, url);
+        return Err(http::Error::Unknown);
+    }
+    Ok(Some(body))
+}
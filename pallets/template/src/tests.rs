@@ -1,23 +1,2788 @@
-use crate::{mock::*, Error, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, offchain, AccountOfFid, ArtistStatus, Artists, Auctions, AuctionsEndingAt, Call,
+	Collections, EngagementActions, EngagementAttestation, EngagementVerdict, Error, Event,
+	GatingRule, Listings, NextItemId, Offers, OwnedNfts, PendingEngagementCheck, WeightInfo,
+	KEY_TYPE,
+};
+use codec::Decode;
+use frame_support::{
+	assert_noop, assert_ok,
+	pallet_prelude::*,
+	traits::{Hooks, UnfilteredDispatchable},
+};
+use sp_core::{
+	ed25519,
+	offchain::{
+		testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	Pair,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{traits::ValidateUnsigned, Perbill};
+use std::sync::Arc;
+
+fn register_verified_artist(artist: AccountId) {
+	assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(artist)));
+	assert_ok!(TemplateModule::approve_artist(RuntimeOrigin::root(), artist));
+}
+
+fn setup_collection(artist: AccountId) -> u32 {
+	register_verified_artist(artist);
+	let collection_id = TemplateModule::next_collection_id();
+	assert_ok!(TemplateModule::create_collection(
+		RuntimeOrigin::signed(artist),
+		b"collection".to_vec(),
+		Perbill::zero(),
+		None,
+		None
+	));
+	collection_id
+}
+
+fn create_nft(artist: AccountId, collection_id: u32, price: Balance) -> u32 {
+	let item_id = TemplateModule::next_item_id(collection_id);
+	assert_ok!(TemplateModule::create_nft(
+		RuntimeOrigin::signed(artist),
+		collection_id,
+		b"ipfs://item".to_vec(),
+		price
+	));
+	item_id
+}
+
+#[test]
+fn register_artist_charges_fee_once() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(TemplateModule::artists(ALICE), Some(ArtistStatus::Pending));
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 100);
+		System::assert_last_event(Event::ArtistRegistered(ALICE).into());
+
+		assert_noop!(
+			TemplateModule::register_artist(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::AlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn create_collection_requires_artist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::create_collection(
+				RuntimeOrigin::signed(BOB),
+				b"collection".to_vec(),
+				Perbill::zero(),
+				None,
+				None
+			),
+			Error::<Test>::NotRegisteredArtist
+		);
+
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(BOB)));
+		assert_noop!(
+			TemplateModule::create_collection(
+				RuntimeOrigin::signed(BOB),
+				b"collection".to_vec(),
+				Perbill::zero(),
+				None,
+				None
+			),
+			Error::<Test>::ArtistNotVerified
+		);
+	});
+}
+
+#[test]
+fn create_nft_indexes_owner() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+
+		assert!(OwnedNfts::<Test>::contains_key(ALICE, (collection_id, item_id)));
+		assert_eq!(TemplateModule::collections(collection_id).unwrap().items, 1);
+		System::assert_last_event(Event::NFTMinted(collection_id, item_id, ALICE).into());
+	});
+}
+
+#[test]
+fn only_the_creator_mints_into_a_collection() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+
+		assert_noop!(
+			TemplateModule::create_nft(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				b"x".to_vec(),
+				10
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			TemplateModule::batch_create_nfts(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				vec![(b"x".to_vec(), 10)]
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			TemplateModule::create_nft(RuntimeOrigin::signed(BOB), 9, b"x".to_vec(), 10),
+			Error::<Test>::CollectionNotFound
+		);
+	});
+}
+
+#[test]
+fn transfer_nft_moves_owner_index() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+
+		assert_noop!(
+			TemplateModule::transfer_nft(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				CHARLIE
+			),
+			Error::<Test>::NotNFTOwner
+		);
+		assert_ok!(TemplateModule::transfer_nft(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id,
+			BOB
+		));
+
+		assert!(!OwnedNfts::<Test>::contains_key(ALICE, (collection_id, item_id)));
+		assert!(OwnedNfts::<Test>::contains_key(BOB, (collection_id, item_id)));
+		assert_eq!(TemplateModule::nfts(collection_id, item_id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn mint_nft_pays_seller_and_moves_owner_index() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+		let alice_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			None
+		));
+
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 50);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 50);
+		assert!(OwnedNfts::<Test>::contains_key(BOB, (collection_id, item_id)));
+		assert!(!OwnedNfts::<Test>::contains_key(ALICE, (collection_id, item_id)));
+		assert!(TemplateModule::listings(collection_id, item_id).is_none());
+		assert_noop!(
+			TemplateModule::mint_nft(RuntimeOrigin::signed(CHARLIE), collection_id, item_id, None),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn burn_nft_clears_owner_index() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+
+		assert_ok!(TemplateModule::burn_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id));
+
+		assert!(TemplateModule::nfts(collection_id, item_id).is_none());
+		assert!(!OwnedNfts::<Test>::contains_key(ALICE, (collection_id, item_id)));
+		assert_eq!(TemplateModule::collections(collection_id).unwrap().items, 0);
+	});
+}
+
+#[test]
+fn nfts_of_owner_paginates() {
+	new_test_ext().execute_with(|| {
+		let first = setup_collection(ALICE);
+		let second = TemplateModule::next_collection_id();
+		assert_ok!(TemplateModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			b"second".to_vec(),
+			Perbill::zero(),
+			None,
+			None
+		));
+		for _ in 0..3 {
+			create_nft(ALICE, first, 10);
+			create_nft(ALICE, second, 10);
+		}
+		let sold = create_nft(ALICE, first, 10);
+		assert_ok!(TemplateModule::transfer_nft(RuntimeOrigin::signed(ALICE), first, sold, BOB));
+
+		let all = TemplateModule::nfts_of_owner(ALICE, None, 100);
+		assert_eq!(all.len(), 6);
+		assert!(all.iter().all(|(_, _, nft)| nft.owner == ALICE));
+
+		let page = TemplateModule::nfts_of_owner(ALICE, None, 4);
+		let (collection_id, item_id, _) = page.last().cloned().unwrap();
+		let rest = TemplateModule::nfts_of_owner(ALICE, Some((collection_id, item_id)), 4);
+		assert_eq!(page.len(), 4);
+		assert_eq!(rest.len(), 2);
+
+		let mut seen: Vec<_> = page.iter().chain(rest.iter()).map(|(c, i, _)| (*c, *i)).collect();
+		seen.sort();
+		let mut expected: Vec<_> = all.iter().map(|(c, i, _)| (*c, *i)).collect();
+		expected.sort();
+		assert_eq!(seen, expected);
+	});
+}
+
+#[test]
+fn collection_items_paginates() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		for _ in 0..5 {
+			create_nft(ALICE, collection_id, 10);
+		}
+
+		let page = TemplateModule::collection_items(collection_id, None, 3);
+		let rest = TemplateModule::collection_items(collection_id, page.last().map(|(i, _)| *i), 3);
+		assert_eq!(page.len(), 3);
+		assert_eq!(rest.len(), 2);
+
+		let mut seen: Vec<_> = page.iter().chain(rest.iter()).map(|(i, _)| *i).collect();
+		seen.sort();
+		assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+		assert_eq!(TemplateModule::nft_details(collection_id, 4).unwrap().owner, ALICE);
+		assert!(TemplateModule::nft_details(collection_id, 5).is_none());
+	});
+}
+
+#[test]
+fn list_collections_paginates() {
+	new_test_ext().execute_with(|| {
+		setup_collection(ALICE);
+		for _ in 0..4 {
+			assert_ok!(TemplateModule::create_collection(
+				RuntimeOrigin::signed(ALICE),
+				b"more".to_vec(),
+				Perbill::zero(),
+				None,
+				None
+			));
+		}
+
+		let page = TemplateModule::list_collections(None, 3);
+		let rest = TemplateModule::list_collections(page.last().map(|(c, _)| *c), 3);
+		assert_eq!(page.len(), 3);
+		assert_eq!(rest.len(), 2);
+
+		let mut seen: Vec<_> = page.iter().chain(rest.iter()).map(|(c, _)| *c).collect();
+		seen.sort();
+		assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+	});
+}
+
+#[test]
+fn sold_nft_can_be_relisted_and_resold() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+		assert_eq!(Listings::<Test>::get(collection_id, item_id), Some(50));
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			None
+		));
+
+		assert_noop!(
+			TemplateModule::list_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id, 80),
+			Error::<Test>::NotNFTOwner
+		);
+		assert_ok!(TemplateModule::list_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			80
+		));
+		System::assert_last_event(Event::NFTListed(collection_id, item_id, 80).into());
+		assert_noop!(
+			TemplateModule::list_nft(RuntimeOrigin::signed(BOB), collection_id, item_id, 90),
+			Error::<Test>::AlreadyListed
+		);
+
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(CHARLIE),
+			collection_id,
+			item_id,
+			None
+		));
+		assert_eq!(TemplateModule::nfts(collection_id, item_id).unwrap().owner, CHARLIE);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 50 + 80);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 80);
+	});
+}
+
+#[test]
+fn update_price_and_delist() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+
+		assert_noop!(
+			TemplateModule::update_price(RuntimeOrigin::signed(BOB), collection_id, item_id, 70),
+			Error::<Test>::NotNFTOwner
+		);
+		assert_ok!(TemplateModule::update_price(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id,
+			70
+		));
+		assert_eq!(Listings::<Test>::get(collection_id, item_id), Some(70));
+		assert_eq!(TemplateModule::nfts(collection_id, item_id).unwrap().price, 70);
+		System::assert_last_event(Event::NFTPriceUpdated(collection_id, item_id, 70).into());
+
+		assert_ok!(TemplateModule::delist_nft(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id
+		));
+		System::assert_last_event(Event::NFTDelisted(collection_id, item_id).into());
+		assert_noop!(
+			TemplateModule::delist_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id),
+			Error::<Test>::NotListed
+		);
+		assert_noop!(
+			TemplateModule::update_price(RuntimeOrigin::signed(ALICE), collection_id, item_id, 60),
+			Error::<Test>::NotListed
+		);
+		assert_noop!(
+			TemplateModule::mint_nft(RuntimeOrigin::signed(BOB), collection_id, item_id, None),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn transfer_cancels_listing() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+
+		assert_ok!(TemplateModule::transfer_nft(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id,
+			BOB
+		));
+
+		assert!(Listings::<Test>::get(collection_id, item_id).is_none());
+		assert_noop!(
+			TemplateModule::mint_nft(RuntimeOrigin::signed(CHARLIE), collection_id, item_id, None),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn royalty_is_capped_and_creator_only() {
+	new_test_ext().execute_with(|| {
+		register_verified_artist(ALICE);
+		assert_noop!(
+			TemplateModule::create_collection(
+				RuntimeOrigin::signed(ALICE),
+				b"collection".to_vec(),
+				Perbill::from_percent(30),
+				None,
+				None
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_ok!(TemplateModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			b"collection".to_vec(),
+			Perbill::from_percent(10),
+			None,
+			None
+		));
+		let collection = TemplateModule::collections(0).unwrap();
+		assert_eq!(collection.royalty, Perbill::from_percent(10));
+		assert_eq!(collection.royalty_recipient, ALICE);
+
+		assert_noop!(
+			TemplateModule::set_collection_royalty(
+				RuntimeOrigin::signed(BOB),
+				0,
+				Perbill::from_percent(5),
+				BOB
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			TemplateModule::set_collection_royalty(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				Perbill::from_percent(26),
+				ALICE
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_ok!(TemplateModule::set_collection_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			Perbill::from_percent(5),
+			CHARLIE
+		));
+		System::assert_last_event(
+			Event::CollectionRoyaltySet(0, Perbill::from_percent(5), CHARLIE).into(),
+		);
+	});
+}
+
+#[test]
+fn resale_pays_royalty_to_recipient() {
+	new_test_ext().execute_with(|| {
+		register_verified_artist(ALICE);
+		assert_ok!(TemplateModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			b"collection".to_vec(),
+			Perbill::from_percent(10),
+			None,
+			None
+		));
+		let item_id = create_nft(ALICE, 0, 1_000);
+		assert_ok!(TemplateModule::mint_nft(RuntimeOrigin::signed(BOB), 0, item_id, None));
+		assert_ok!(TemplateModule::list_nft(RuntimeOrigin::signed(BOB), 0, item_id, 2_000));
+
+		let alice_balance = Balances::free_balance(ALICE);
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(TemplateModule::mint_nft(RuntimeOrigin::signed(CHARLIE), 0, item_id, None));
+
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 200);
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 1_800);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 2_000);
+		System::assert_has_event(Event::RoyaltyPaid(0, item_id, ALICE, 200).into());
+	});
+}
+
+#[test]
+fn royalty_below_existential_deposit_goes_to_seller() {
+	new_test_ext().execute_with(|| {
+		ExistentialDeposit::set(50);
+		let collection_id = setup_collection(ALICE);
+		// Account 7 does not exist, so it cannot take less than the existential deposit
+		assert_ok!(TemplateModule::set_collection_royalty(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Perbill::from_percent(1),
+			7
+		));
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		let alice_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			None
+		));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 1_000);
+		assert_eq!(Balances::free_balance(7), 0);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::TemplateModule(Event::RoyaltyPaid(..))
+		)));
+
+		// A royalty large enough to open the account is paid
+		assert_ok!(TemplateModule::list_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			5_000
+		));
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(CHARLIE),
+			collection_id,
+			item_id,
+			None
+		));
+		assert_eq!(Balances::free_balance(7), 50);
+		System::assert_has_event(Event::RoyaltyPaid(collection_id, item_id, 7, 50).into());
+	});
+}
+
+#[test]
+fn sale_pays_marketplace_fee_and_admin_withdraws() {
+	new_test_ext().execute_with(|| {
+		MarketplaceFee::set(Perbill::from_percent(5));
+		let pallet_account = TemplateModule::account_id();
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		let alice_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			None
+		));
+
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 950);
+		// Registration fee plus the 5% cut of the sale
+		assert_eq!(Balances::free_balance(pallet_account), 100 + 50);
+		System::assert_has_event(Event::MarketplaceFeePaid(collection_id, item_id, 50).into());
+
+		assert_noop!(
+			TemplateModule::withdraw_fees(RuntimeOrigin::signed(ALICE), CHARLIE, 50),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::withdraw_fees(RuntimeOrigin::root(), CHARLIE, 50));
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE + 50);
+		assert_eq!(Balances::free_balance(pallet_account), 100);
+		System::assert_last_event(Event::FeesWithdrawn(CHARLIE, 50).into());
+	});
+}
+
+/// Create a collection with one item and put it up for auction from block 1 to 101.
+fn setup_auction(reserve: Balance) -> (u32, u32) {
+	let collection_id = setup_collection(ALICE);
+	let item_id = create_nft(ALICE, collection_id, 1_000);
+	assert_ok!(TemplateModule::delist_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id));
+	assert_ok!(TemplateModule::create_auction(
+		RuntimeOrigin::signed(ALICE),
+		collection_id,
+		item_id,
+		reserve,
+		100
+	));
+	(collection_id, item_id)
+}
+
+#[test]
+fn create_auction_checks_duration_and_listing() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		let create = |duration| {
+			TemplateModule::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				collection_id,
+				item_id,
+				500,
+				duration,
+			)
+		};
+
+		assert_noop!(create(100), Error::<Test>::AlreadyListed);
+		assert_ok!(TemplateModule::delist_nft(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id
+		));
+		assert_noop!(create(9), Error::<Test>::InvalidAuctionDuration);
+		assert_noop!(create(1_001), Error::<Test>::InvalidAuctionDuration);
+		assert_noop!(
+			TemplateModule::create_auction(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				500,
+				100
+			),
+			Error::<Test>::NotNFTOwner
+		);
+
+		assert_ok!(create(100));
+		System::assert_last_event(Event::AuctionCreated(collection_id, item_id, 500, 101).into());
+		assert_eq!(AuctionsEndingAt::<Test>::get(101).into_inner(), vec![(collection_id, item_id)]);
+		assert_noop!(create(100), Error::<Test>::NftInAuction);
+	});
+}
+
+#[test]
+fn auctioned_nft_is_locked() {
+	new_test_ext().execute_with(|| {
+		let (collection_id, item_id) = setup_auction(500);
+
+		assert_noop!(
+			TemplateModule::transfer_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id, BOB),
+			Error::<Test>::NftInAuction
+		);
+		assert_noop!(
+			TemplateModule::burn_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id),
+			Error::<Test>::NftInAuction
+		);
+		assert_noop!(
+			TemplateModule::list_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id, 10),
+			Error::<Test>::NftInAuction
+		);
+	});
+}
+
+#[test]
+fn outbid_bidder_is_refunded() {
+	new_test_ext().execute_with(|| {
+		let (collection_id, item_id) = setup_auction(500);
+		let bid = |who, amount| {
+			TemplateModule::bid(RuntimeOrigin::signed(who), collection_id, item_id, amount, None)
+		};
+
+		assert_noop!(bid(ALICE, 600), Error::<Test>::SellerCannotBid);
+		assert_noop!(bid(BOB, 499), Error::<Test>::BidBelowReserve);
+		assert_ok!(bid(BOB, 500));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 500);
+		assert_eq!(Balances::reserved_balance(BOB), 500);
+		System::assert_last_event(Event::BidPlaced(collection_id, item_id, BOB, 500).into());
+
+		assert_noop!(bid(CHARLIE, 500), Error::<Test>::BidTooLow);
+		assert_ok!(bid(CHARLIE, 700));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 700);
+		assert_eq!(
+			TemplateModule::auctions(collection_id, item_id).unwrap().highest_bid,
+			Some((CHARLIE, 700))
+		);
+	});
+}
+
+#[test]
+fn late_bid_extends_auction() {
+	new_test_ext().execute_with(|| {
+		let (collection_id, item_id) = setup_auction(500);
+
+		run_to_block(98);
+		assert_ok!(TemplateModule::bid(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			500,
+			None
+		));
+		System::assert_has_event(Event::AuctionExtended(collection_id, item_id, 103).into());
+		assert_eq!(TemplateModule::auctions(collection_id, item_id).unwrap().end, 103);
+		assert!(AuctionsEndingAt::<Test>::get(101).is_empty());
+		assert_eq!(AuctionsEndingAt::<Test>::get(103).into_inner(), vec![(collection_id, item_id)]);
+
+		run_to_block(101);
+		assert!(Auctions::<Test>::contains_key(collection_id, item_id));
+		run_to_block(103);
+		assert_noop!(
+			TemplateModule::bid(RuntimeOrigin::signed(CHARLIE), collection_id, item_id, 800, None),
+			Error::<Test>::AuctionNotFound
+		);
+		assert_eq!(TemplateModule::nfts(collection_id, item_id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn auction_settles_to_highest_bidder() {
+	new_test_ext().execute_with(|| {
+		MarketplaceFee::set(Perbill::from_percent(5));
+		let (collection_id, item_id) = setup_auction(500);
+		assert_ok!(TemplateModule::set_collection_royalty(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Perbill::from_percent(10),
+			CHARLIE
+		));
+		assert_ok!(TemplateModule::bid(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			1_000,
+			None
+		));
+		let alice_balance = Balances::free_balance(ALICE);
+
+		run_to_block(100);
+		assert!(Auctions::<Test>::contains_key(collection_id, item_id));
+		run_to_block(101);
+
+		assert!(!Auctions::<Test>::contains_key(collection_id, item_id));
+		let nft = TemplateModule::nfts(collection_id, item_id).unwrap();
+		assert_eq!((nft.owner, nft.price, nft.is_sold), (BOB, 1_000, true));
+		assert!(OwnedNfts::<Test>::contains_key(BOB, (collection_id, item_id)));
+		assert!(!OwnedNfts::<Test>::contains_key(ALICE, (collection_id, item_id)));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 1_000);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE + 100);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 850);
+		System::assert_has_event(Event::AuctionSettled(collection_id, item_id, BOB, 1_000).into());
+	});
+}
+
+#[test]
+fn auction_without_bids_ends_or_is_cancelled() {
+	new_test_ext().execute_with(|| {
+		let (collection_id, item_id) = setup_auction(500);
+		run_to_block(101);
+		System::assert_last_event(Event::AuctionCancelled(collection_id, item_id).into());
+		assert_eq!(TemplateModule::nfts(collection_id, item_id).unwrap().owner, ALICE);
+
+		assert_ok!(TemplateModule::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id,
+			500,
+			100
+		));
+		assert_noop!(
+			TemplateModule::cancel_auction(RuntimeOrigin::signed(BOB), collection_id, item_id),
+			Error::<Test>::NotNFTOwner
+		);
+		assert_ok!(TemplateModule::cancel_auction(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id
+		));
+		assert!(AuctionsEndingAt::<Test>::get(201).is_empty());
+
+		assert_ok!(TemplateModule::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id,
+			500,
+			100
+		));
+		assert_ok!(TemplateModule::bid(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			500,
+			None
+		));
+		assert_noop!(
+			TemplateModule::cancel_auction(RuntimeOrigin::signed(ALICE), collection_id, item_id),
+			Error::<Test>::AuctionHasBids
+		);
+	});
+}
+
+#[test]
+fn offer_on_sold_nft_is_accepted() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			None
+		));
+
+		assert_noop!(
+			TemplateModule::make_offer(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				500,
+				50,
+				None
+			),
+			Error::<Test>::CannotOfferOnOwnNft
+		);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			collection_id,
+			item_id,
+			1_500,
+			50,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 1_500);
+		System::assert_last_event(
+			Event::OfferMade(collection_id, item_id, CHARLIE, 1_500, 50).into(),
+		);
+
+		assert_noop!(
+			TemplateModule::accept_offer(
+				RuntimeOrigin::signed(ALICE),
+				collection_id,
+				item_id,
+				CHARLIE
+			),
+			Error::<Test>::NotNFTOwner
+		);
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(TemplateModule::accept_offer(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			CHARLIE
+		));
+
+		let nft = TemplateModule::nfts(collection_id, item_id).unwrap();
+		assert_eq!((nft.owner, nft.price), (CHARLIE, 1_500));
+		assert!(OwnedNfts::<Test>::contains_key(CHARLIE, (collection_id, item_id)));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 1_500);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), INITIAL_BALANCE - 1_500);
+		assert!(Offers::<Test>::get((collection_id, item_id, CHARLIE)).is_none());
+		System::assert_last_event(
+			Event::OfferAccepted(collection_id, item_id, BOB, CHARLIE, 1_500).into(),
+		);
+	});
+}
+
+#[test]
+fn new_offer_replaces_previous_and_can_be_withdrawn() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			300,
+			50,
+			None
+		));
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			400,
+			60,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 400);
+		assert_eq!(TemplateModule::offer_count(collection_id, item_id), 1);
+
+		assert_ok!(TemplateModule::withdraw_offer(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id
+		));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		System::assert_last_event(Event::OfferWithdrawn(collection_id, item_id, BOB).into());
+		assert_eq!(TemplateModule::offer_count(collection_id, item_id), 0);
+		assert_noop!(
+			TemplateModule::withdraw_offer(RuntimeOrigin::signed(BOB), collection_id, item_id),
+			Error::<Test>::OfferNotFound
+		);
+	});
+}
+
+#[test]
+fn offers_per_item_are_capped() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		for buyer in [BOB, CHARLIE, 4, 5] {
+			assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), buyer, INITIAL_BALANCE));
+		}
+		for buyer in [BOB, CHARLIE, 4] {
+			assert_ok!(TemplateModule::make_offer(
+				RuntimeOrigin::signed(buyer),
+				collection_id,
+				item_id,
+				300,
+				50,
+				None
+			));
+		}
+
+		assert_noop!(
+			TemplateModule::make_offer(
+				RuntimeOrigin::signed(5),
+				collection_id,
+				item_id,
+				300,
+				50,
+				None
+			),
+			Error::<Test>::TooManyOffers
+		);
+		// Replacing an offer does not take another slot
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			400,
+			50,
+			None
+		));
+		assert_ok!(TemplateModule::withdraw_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			collection_id,
+			item_id
+		));
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(5),
+			collection_id,
+			item_id,
+			300,
+			50,
+			None
+		));
+		assert_eq!(TemplateModule::offer_count(collection_id, item_id), 3);
+	});
+}
+
+#[test]
+fn offer_amount_and_expiry_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		let offer = |amount, expiry| {
+			TemplateModule::make_offer(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				amount,
+				expiry,
+				None,
+			)
+		};
+
+		assert_noop!(offer(9, 50), Error::<Test>::OfferTooLow);
+		// Block 1 plus `MaxOfferDuration`
+		assert_noop!(offer(10, 102), Error::<Test>::OfferTooLong);
+		assert_ok!(offer(10, 101));
+		assert_eq!(TemplateModule::offers((collection_id, item_id, BOB)).unwrap().expiry, 101);
+	});
+}
+
+#[test]
+fn offers_are_cancelled_with_their_item() {
+	use frame_support::traits::tokens::nonfungibles_v2::Mutate;
+
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let burned = create_nft(ALICE, collection_id, 1_000);
+		let deleted = create_nft(ALICE, collection_id, 1_000);
+		for (buyer, item_id) in [(BOB, burned), (CHARLIE, burned), (BOB, deleted)] {
+			assert_ok!(TemplateModule::make_offer(
+				RuntimeOrigin::signed(buyer),
+				collection_id,
+				item_id,
+				300,
+				50,
+				None
+			));
+		}
+
+		assert_ok!(TemplateModule::burn_nft(RuntimeOrigin::signed(ALICE), collection_id, burned));
+		assert_eq!(Offers::<Test>::iter_prefix((collection_id, burned)).count(), 0);
+		assert_eq!(TemplateModule::offer_count(collection_id, burned), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 300);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+
+		// An item minted at the id of the burned one starts without offers
+		assert_ok!(TemplateModule::mint_into(&collection_id, &burned, &ALICE, &(), false));
+		assert_noop!(
+			TemplateModule::accept_offer(RuntimeOrigin::signed(ALICE), collection_id, burned, BOB),
+			Error::<Test>::OfferNotFound
+		);
+
+		assert_ok!(TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id));
+		assert!(TemplateModule::collections(collection_id).is_none());
+		assert_eq!(Offers::<Test>::iter().count(), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn expired_offer_cannot_be_accepted_and_is_cleared() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		assert_noop!(
+			TemplateModule::make_offer(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				300,
+				0,
+				None
+			),
+			Error::<Test>::OfferExpired
+		);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			300,
+			10,
+			None
+		));
+
+		assert_noop!(
+			TemplateModule::clear_expired_offer(
+				RuntimeOrigin::signed(CHARLIE),
+				collection_id,
+				item_id,
+				BOB
+			),
+			Error::<Test>::OfferNotExpired
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			TemplateModule::accept_offer(RuntimeOrigin::signed(ALICE), collection_id, item_id, BOB),
+			Error::<Test>::OfferExpired
+		);
+		assert_ok!(TemplateModule::clear_expired_offer(
+			RuntimeOrigin::signed(CHARLIE),
+			collection_id,
+			item_id,
+			BOB
+		));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		System::assert_last_event(Event::OfferExpired(collection_id, item_id, BOB).into());
+	});
+}
+
+#[test]
+fn batch_create_allocates_contiguous_ids() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		create_nft(ALICE, collection_id, 1_000);
+
+		assert_ok!(TemplateModule::batch_create_nfts(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			vec![(b"one".to_vec(), 10), (b"two".to_vec(), 20), (b"three".to_vec(), 30)]
+		));
+
+		System::assert_last_event(
+			Event::NFTBatchMinted(collection_id, vec![1, 2, 3], ALICE).into(),
+		);
+		assert_eq!(TemplateModule::next_item_id(collection_id), 4);
+		assert_eq!(TemplateModule::collections(collection_id).unwrap().items, 4);
+		assert_eq!(TemplateModule::nfts(collection_id, 3).unwrap().metadata.into_inner(), b"three");
+		assert_eq!(Listings::<Test>::get(collection_id, 2), Some(20));
+		assert!(OwnedNfts::<Test>::contains_key(ALICE, (collection_id, 1)));
+	});
+}
+
+#[test]
+fn batch_create_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let batch = |items: Vec<(Vec<u8>, Balance)>| {
+			TemplateModule::batch_create_nfts(RuntimeOrigin::signed(ALICE), collection_id, items)
+		};
+
+		assert_noop!(batch(vec![]), Error::<Test>::EmptyBatch);
+		assert_noop!(batch(vec![(b"item".to_vec(), 10); 6]), Error::<Test>::BatchTooLarge);
+		assert_noop!(
+			batch(vec![(b"item".to_vec(), 10), (vec![0u8; 257], 10)]),
+			Error::<Test>::MetadataInvalid
+		);
+		assert_eq!(TemplateModule::next_item_id(collection_id), 0);
+		assert!(TemplateModule::nfts(collection_id, 0).is_none());
+	});
+}
+
+#[test]
+fn frozen_collection_rejects_new_items_edits_and_burns() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+
+		assert_noop!(
+			TemplateModule::freeze_collection(RuntimeOrigin::signed(BOB), collection_id),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_ok!(TemplateModule::freeze_collection(RuntimeOrigin::signed(ALICE), collection_id));
+		System::assert_last_event(Event::CollectionFrozen(collection_id).into());
+		assert!(TemplateModule::collections(collection_id).unwrap().is_frozen);
+
+		assert_noop!(
+			TemplateModule::create_nft(
+				RuntimeOrigin::signed(ALICE),
+				collection_id,
+				b"x".to_vec(),
+				1
+			),
+			Error::<Test>::CollectionFrozen
+		);
+		assert_noop!(
+			TemplateModule::batch_create_nfts(
+				RuntimeOrigin::signed(ALICE),
+				collection_id,
+				vec![(b"x".to_vec(), 1)]
+			),
+			Error::<Test>::CollectionFrozen
+		);
+		assert_noop!(
+			TemplateModule::update_collection_metadata(
+				RuntimeOrigin::signed(ALICE),
+				collection_id,
+				b"new".to_vec()
+			),
+			Error::<Test>::CollectionFrozen
+		);
+		assert_noop!(
+			TemplateModule::update_nft_metadata(
+				RuntimeOrigin::signed(ALICE),
+				collection_id,
+				item_id,
+				b"new".to_vec()
+			),
+			Error::<Test>::CollectionFrozen
+		);
+		assert_noop!(
+			TemplateModule::burn_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id),
+			Error::<Test>::CollectionFrozen
+		);
+
+		assert_ok!(TemplateModule::unfreeze_collection(
+			RuntimeOrigin::signed(ALICE),
+			collection_id
+		));
+		System::assert_last_event(Event::CollectionUnfrozen(collection_id).into());
+		assert_noop!(
+			TemplateModule::unfreeze_collection(RuntimeOrigin::signed(ALICE), collection_id),
+			Error::<Test>::CollectionNotFrozen
+		);
+		assert_ok!(TemplateModule::update_collection_metadata(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			b"new".to_vec()
+		));
+		assert_eq!(
+			TemplateModule::collections(collection_id).unwrap().metadata.into_inner(),
+			b"new"
+		);
+		System::assert_last_event(Event::CollectionUpdated(collection_id).into());
+		assert_ok!(TemplateModule::burn_nft(RuntimeOrigin::signed(ALICE), collection_id, item_id));
+	});
+}
+
+#[test]
+fn nft_metadata_is_editable_until_locked_or_sold() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		let sold_id = create_nft(ALICE, collection_id, 1_000);
+		let update = |who, item_id| {
+			TemplateModule::update_nft_metadata(
+				RuntimeOrigin::signed(who),
+				collection_id,
+				item_id,
+				b"ipfs://v2".to_vec(),
+			)
+		};
+
+		assert_noop!(update(BOB, item_id), Error::<Test>::NotCollectionOwner);
+		assert_ok!(update(ALICE, item_id));
+		assert_eq!(
+			TemplateModule::nfts(collection_id, item_id).unwrap().metadata.into_inner(),
+			b"ipfs://v2"
+		);
+		System::assert_last_event(Event::NFTMetadataUpdated(collection_id, item_id).into());
+
+		assert_ok!(TemplateModule::lock_nft_metadata(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			item_id
+		));
+		System::assert_last_event(Event::NFTMetadataLocked(collection_id, item_id).into());
+		assert_noop!(update(ALICE, item_id), Error::<Test>::MetadataLocked);
+
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			sold_id,
+			None
+		));
+		assert_noop!(update(ALICE, sold_id), Error::<Test>::NotNFTOwner);
+	});
+}
+
+#[test]
+fn delete_collection_refuses_items_owned_by_others() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			None
+		));
+
+		assert_noop!(
+			TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id),
+			Error::<Test>::ItemsOwnedByOthers
+		);
+		assert_noop!(
+			TemplateModule::destroy_collection(RuntimeOrigin::signed(ALICE), collection_id, 1),
+			Error::<Test>::ItemsOwnedByOthers
+		);
+	});
+}
+
+#[test]
+fn delete_collection_removes_creator_items_in_chunks() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		for _ in 0..3 {
+			create_nft(ALICE, collection_id, 1_000);
+		}
+
+		assert_noop!(
+			TemplateModule::delete_collection(RuntimeOrigin::signed(BOB), collection_id),
+			Error::<Test>::NotCollectionOwner
+		);
+		let info =
+			TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::destroy_collection(2)));
+		System::assert_last_event(Event::CollectionItemsRemoved(collection_id, 2, 1).into());
+		let collection = TemplateModule::collections(collection_id).unwrap();
+		assert_eq!(collection.items, 1);
+		assert!(collection.is_frozen);
+		let left: Vec<_> = TemplateModule::collection_items(collection_id, None, 10)
+			.into_iter()
+			.map(|(item_id, _)| item_id)
+			.collect();
+		assert_eq!(left.len(), 1);
+		for item_id in (0..3).filter(|item_id| !left.contains(item_id)) {
+			assert!(!OwnedNfts::<Test>::contains_key(ALICE, (collection_id, item_id)));
+			assert!(Listings::<Test>::get(collection_id, item_id).is_none());
+		}
+
+		let info =
+			TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::destroy_collection(1)));
+		System::assert_last_event(Event::CollectionDeleted(collection_id).into());
+		assert!(TemplateModule::collections(collection_id).is_none());
+		assert!(TemplateModule::nfts(collection_id, left[0]).is_none());
+		assert_eq!(TemplateModule::nfts_of_owner(ALICE, None, 10), vec![]);
+	});
+}
+
+#[test]
+fn delete_collection_checks_every_chunk_before_removing_anything() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		for _ in 0..5 {
+			create_nft(ALICE, collection_id, 1_000);
+		}
+		// The item removed in the last of three chunks goes to someone else
+		let (last, _) = TemplateModule::collection_items(collection_id, None, 10).pop().unwrap();
+		assert_ok!(TemplateModule::transfer_nft(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			last,
+			BOB
+		));
+		assert_eq!(TemplateModule::foreign_items(collection_id), 1);
+
+		assert_noop!(
+			TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id),
+			Error::<Test>::ItemsOwnedByOthers
+		);
+		assert_noop!(
+			TemplateModule::destroy_collection(RuntimeOrigin::signed(ALICE), collection_id, 5),
+			Error::<Test>::ItemsOwnedByOthers
+		);
+		let collection = TemplateModule::collections(collection_id).unwrap();
+		assert_eq!(collection.items, 5);
+		assert!(!collection.is_frozen);
+
+		// Once the item is back with the creator, deletion runs through every chunk
+		assert_ok!(TemplateModule::transfer_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			last,
+			ALICE
+		));
+		assert_eq!(TemplateModule::foreign_items(collection_id), 0);
+		for left in [3, 1] {
+			assert_ok!(TemplateModule::delete_collection(
+				RuntimeOrigin::signed(ALICE),
+				collection_id
+			));
+			System::assert_last_event(Event::CollectionItemsRemoved(collection_id, 2, left).into());
+		}
+		assert_ok!(TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id));
+		System::assert_last_event(Event::CollectionDeleted(collection_id).into());
+	});
+}
+
+#[test]
+fn delete_collection_refuses_items_in_auction() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		for _ in 0..5 {
+			create_nft(ALICE, collection_id, 1_000);
+		}
+		let (last, _) = TemplateModule::collection_items(collection_id, None, 10).pop().unwrap();
+		assert_ok!(TemplateModule::delist_nft(RuntimeOrigin::signed(ALICE), collection_id, last));
+		assert_ok!(TemplateModule::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			last,
+			100,
+			100
+		));
+
+		assert_noop!(
+			TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id),
+			Error::<Test>::NftInAuction
+		);
+	});
+}
+
+#[test]
+fn destroy_collection_checks_witness() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		for _ in 0..3 {
+			create_nft(ALICE, collection_id, 1_000);
+		}
+
+		assert_noop!(
+			TemplateModule::destroy_collection(RuntimeOrigin::signed(ALICE), collection_id, 2),
+			Error::<Test>::BadWitness
+		);
+
+		// Each step removes at most `RemoveItemsLimit` items and charges only those
+		let info =
+			TemplateModule::destroy_collection(RuntimeOrigin::signed(ALICE), collection_id, 3)
+				.unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::destroy_collection(2)));
+		System::assert_last_event(Event::CollectionItemsRemoved(collection_id, 2, 1).into());
+		assert!(TemplateModule::collections(collection_id).unwrap().is_frozen);
+
+		let info =
+			TemplateModule::destroy_collection(RuntimeOrigin::signed(ALICE), collection_id, 1)
+				.unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::destroy_collection(1)));
+		System::assert_last_event(Event::CollectionDeleted(collection_id).into());
+		assert!(TemplateModule::collections(collection_id).is_none());
+		assert_eq!(TemplateModule::collection_items(collection_id, None, 10), vec![]);
+		assert_eq!(TemplateModule::next_item_id(collection_id), 0);
+	});
+}
+
+fn set_deposits(collection: Balance, item: Balance, per_byte: Balance) {
+	CollectionDeposit::set(collection);
+	ItemDeposit::set(item);
+	DepositPerByte::set(per_byte);
+}
+
+#[test]
+fn deposits_are_held_and_released_on_burn_and_delete() {
+	new_test_ext().execute_with(|| {
+		set_deposits(50, 20, 1);
+		// Registration fee of 100, then 50 + 10 bytes of metadata for the collection
+		let collection_id = setup_collection(ALICE);
+		assert_eq!(Balances::reserved_balance(ALICE), 60);
+		assert_eq!(TemplateModule::collection_deposit(collection_id), 60);
+
+		// 20 + 11 bytes per item
+		let first = create_nft(ALICE, collection_id, 1_000);
+		create_nft(ALICE, collection_id, 1_000);
+		assert_eq!(Balances::reserved_balance(ALICE), 60 + 2 * 31);
+
+		assert_ok!(TemplateModule::burn_nft(RuntimeOrigin::signed(ALICE), collection_id, first));
+		assert_eq!(Balances::reserved_balance(ALICE), 60 + 31);
+		assert_eq!(TemplateModule::item_deposit(collection_id, first), 0);
+
+		assert_ok!(TemplateModule::update_collection_metadata(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			b"c".to_vec()
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 51 + 31);
+
+		assert_ok!(TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 100);
+	});
+}
+
+#[test]
+fn item_deposit_follows_owner() {
+	new_test_ext().execute_with(|| {
+		set_deposits(0, 20, 0);
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 1_000);
+		assert_eq!(Balances::reserved_balance(ALICE), 20);
+
+		assert_ok!(TemplateModule::mint_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 20);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE - 1_000 - 20);
+
+		assert_ok!(TemplateModule::transfer_nft(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			CHARLIE
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 20);
+	});
+}
+
+#[test]
+fn creating_without_funds_for_deposit_fails() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		set_deposits(0, INITIAL_BALANCE, 0);
+		assert!(TemplateModule::create_nft(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			b"item".to_vec(),
+			10
+		)
+		.is_err());
+		assert!(TemplateModule::nfts(collection_id, 0).is_none());
+	});
+}
+
+#[test]
+fn artist_admin_approves_and_revokes() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::approve_artist(RuntimeOrigin::root(), BOB),
+			Error::<Test>::NotRegisteredArtist
+		);
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(BOB)));
+		assert_noop!(
+			TemplateModule::approve_artist(RuntimeOrigin::signed(ALICE), BOB),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(TemplateModule::approve_artist(RuntimeOrigin::root(), BOB));
+		assert_eq!(TemplateModule::artists(BOB), Some(ArtistStatus::Verified));
+		System::assert_last_event(Event::ArtistApproved(BOB).into());
+		assert_noop!(
+			TemplateModule::approve_artist(RuntimeOrigin::root(), BOB),
+			Error::<Test>::ArtistAlreadyVerified
+		);
+
+		assert_ok!(TemplateModule::revoke_artist(RuntimeOrigin::root(), BOB));
+		assert_eq!(TemplateModule::artists(BOB), Some(ArtistStatus::Suspended));
+		System::assert_last_event(Event::ArtistRevoked(BOB).into());
+		assert_noop!(
+			TemplateModule::create_collection(
+				RuntimeOrigin::signed(BOB),
+				b"collection".to_vec(),
+				Perbill::zero(),
+				None,
+				None
+			),
+			Error::<Test>::ArtistNotVerified
+		);
+	});
+}
+
+#[test]
+fn deregister_refunds_bond_once_collections_are_gone() {
+	new_test_ext().execute_with(|| {
+		ArtistBond::set(500);
+		let collection_id = setup_collection(ALICE);
+		assert_eq!(Balances::reserved_balance(ALICE), 500);
+		assert_eq!(TemplateModule::artist_collection_count(ALICE), 1);
+
+		assert_noop!(
+			TemplateModule::deregister_artist(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::ArtistHasCollections
+		);
+		assert_ok!(TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id));
+		// The bond held at registration comes back in full after `ArtistBond` changes
+		ArtistBond::set(200);
+		assert_ok!(TemplateModule::deregister_artist(RuntimeOrigin::signed(ALICE)));
+
+		assert_eq!(TemplateModule::artists(ALICE), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		// Only the registration fee is kept
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 100);
+		System::assert_last_event(Event::ArtistDeregistered(ALICE).into());
+		assert_noop!(
+			TemplateModule::deregister_artist(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NotRegisteredArtist
+		);
+	});
+}
+
+/// Signer key registered for `fid` in tests.
+fn fid_signer(fid: u64) -> ed25519::Pair {
+	ed25519::Pair::from_seed(&[fid as u8; 32])
+}
+
+/// Sign the current `link_fid` payload of `who` with `signer`.
+fn sign_link(signer: &ed25519::Pair, fid: u64, who: AccountId) -> ed25519::Signature {
+	let nonce = TemplateModule::fid_link_nonce(who);
+	signer.sign(&TemplateModule::fid_link_payload(fid, &who, nonce))
+}
+
+/// Make `signer` a key of `fid` in the mock's signer registry.
+fn add_signer(fid: u64, signer: ed25519::Public) {
+	if !FidSigners::get().contains(&(fid, signer)) {
+		FidSigners::mutate(|signers| signers.push((fid, signer)));
+	}
+}
+
+/// Register a signer key for `fid`, if needed, and link `fid` to `who`.
+fn link_fid(who: AccountId, fid: u64) {
+	let signer = fid_signer(fid);
+	add_signer(fid, signer.public());
+	assert_ok!(TemplateModule::link_fid(
+		RuntimeOrigin::signed(who),
+		fid,
+		signer.public(),
+		sign_link(&signer, fid, who)
+	));
+}
+
+fn set_profile(who: AccountId, fid: Option<u64>) -> sp_runtime::DispatchResult {
+	TemplateModule::set_profile(
+		RuntimeOrigin::signed(who),
+		b"Alice".to_vec(),
+		b"bafkreiavatar".to_vec(),
+		vec![b"https://warpcast.com/alice".to_vec()],
+		fid,
+	)
+}
+
+#[test]
+fn set_profile_indexes_fid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(set_profile(ALICE, Some(42)), Error::<Test>::NotRegisteredArtist);
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(ALICE)));
+		assert_noop!(set_profile(ALICE, Some(42)), Error::<Test>::FidNotLinked);
+		link_fid(ALICE, 42);
+		assert_ok!(set_profile(ALICE, Some(42)));
+		System::assert_last_event(Event::ArtistProfileSet(ALICE).into());
+
+		let profile = TemplateModule::artist_profile(ALICE).unwrap();
+		assert_eq!(profile.display_name.into_inner(), b"Alice");
+		assert_eq!(profile.fid, Some(42));
+		assert_eq!(TemplateModule::artist_by_fid(42).map(|(artist, _)| artist), Some(ALICE));
+
+		// Changing the FID releases the old one
+		link_fid(ALICE, 7);
+		assert_ok!(set_profile(ALICE, Some(7)));
+		assert_eq!(TemplateModule::fid_to_artist(42), None);
+		assert_eq!(TemplateModule::fid_to_artist(7), Some(ALICE));
+
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(BOB)));
+		assert_noop!(set_profile(BOB, Some(7)), Error::<Test>::FidNotLinked);
+
+		assert_ok!(TemplateModule::clear_profile(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(Event::ArtistProfileCleared(ALICE).into());
+		assert_eq!(TemplateModule::artist_profile(ALICE), None);
+		assert_eq!(TemplateModule::artist_by_fid(7), None);
+		link_fid(BOB, 7);
+		assert_ok!(set_profile(BOB, Some(7)));
+		assert_noop!(
+			TemplateModule::clear_profile(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::ProfileNotFound
+		);
+	});
+}
+
+#[test]
+fn profile_fields_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(ALICE)));
+		assert_noop!(
+			TemplateModule::set_profile(
+				RuntimeOrigin::signed(ALICE),
+				vec![b'a'; 65],
+				vec![],
+				vec![],
+				None
+			),
+			Error::<Test>::ProfileFieldTooLong
+		);
+		assert_noop!(
+			TemplateModule::set_profile(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![],
+				vec![b"https://a".to_vec(); 6],
+				None
+			),
+			Error::<Test>::TooManyLinks
+		);
+	});
+}
+
+#[test]
+fn deregistering_clears_profile() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(ALICE)));
+		link_fid(ALICE, 42);
+		assert_ok!(set_profile(ALICE, Some(42)));
+		assert_ok!(TemplateModule::deregister_artist(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(TemplateModule::artist_profile(ALICE), None);
+		assert_eq!(TemplateModule::fid_to_artist(42), None);
+	});
+}
+
+#[test]
+fn link_fid_verifies_signer_and_signature() {
+	new_test_ext().execute_with(|| {
+		let signer = fid_signer(42);
+		let signature = sign_link(&signer, 42, ALICE);
+		assert_noop!(
+			TemplateModule::link_fid(RuntimeOrigin::signed(ALICE), 42, signer.public(), signature),
+			Error::<Test>::UnknownFidSigner
+		);
+
+		add_signer(42, signer.public());
+
+		// Signed for another account, FID or key
+		assert_noop!(
+			TemplateModule::link_fid(RuntimeOrigin::signed(BOB), 42, signer.public(), signature),
+			Error::<Test>::BadFidSignature
+		);
+		assert_noop!(
+			TemplateModule::link_fid(
+				RuntimeOrigin::signed(ALICE),
+				42,
+				signer.public(),
+				sign_link(&signer, 43, ALICE)
+			),
+			Error::<Test>::BadFidSignature
+		);
+		assert_noop!(
+			TemplateModule::link_fid(
+				RuntimeOrigin::signed(ALICE),
+				42,
+				signer.public(),
+				sign_link(&fid_signer(1), 42, ALICE)
+			),
+			Error::<Test>::BadFidSignature
+		);
+
+		assert_ok!(TemplateModule::link_fid(
+			RuntimeOrigin::signed(ALICE),
+			42,
+			signer.public(),
+			signature
+		));
+		System::assert_last_event(Event::FidLinked(42, ALICE).into());
+		assert_eq!(TemplateModule::fid_of(ALICE), Some(42));
+		assert_eq!(TemplateModule::account_of_fid(42), Some(ALICE));
+		assert_eq!(TemplateModule::fid_link_nonce(ALICE), 1);
+	});
+}
+
+#[test]
+fn link_fid_rejects_replayed_signature() {
+	new_test_ext().execute_with(|| {
+		let signer = fid_signer(42);
+		add_signer(42, signer.public());
+		let signature = sign_link(&signer, 42, ALICE);
+		assert_ok!(TemplateModule::link_fid(
+			RuntimeOrigin::signed(ALICE),
+			42,
+			signer.public(),
+			signature
+		));
+
+		assert_ok!(TemplateModule::unlink_fid(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(Event::FidUnlinked(42, ALICE).into());
+		assert_eq!(TemplateModule::fid_of(ALICE), None);
+		assert_eq!(TemplateModule::account_of_fid(42), None);
+
+		assert_noop!(
+			TemplateModule::link_fid(RuntimeOrigin::signed(ALICE), 42, signer.public(), signature),
+			Error::<Test>::BadFidSignature
+		);
+		assert_noop!(
+			TemplateModule::unlink_fid(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::FidNotLinked
+		);
+	});
+}
+
+#[test]
+fn fid_signers_follow_the_signer_registry() {
+	new_test_ext().execute_with(|| {
+		let signer = fid_signer(42);
+		FidSigners::set(vec![(42, signer.public())]);
+		assert!(TemplateModule::is_fid_signer(42, signer.public()));
+		assert!(!TemplateModule::is_fid_signer(43, signer.public()));
+		assert_ok!(TemplateModule::link_fid(
+			RuntimeOrigin::signed(ALICE),
+			42,
+			signer.public(),
+			sign_link(&signer, 42, ALICE)
+		));
+
+		// A key removed from the registry links nothing more, existing links stay
+		FidSigners::set(vec![]);
+		assert!(!TemplateModule::is_fid_signer(42, signer.public()));
+		assert_noop!(
+			TemplateModule::link_fid(
+				RuntimeOrigin::signed(BOB),
+				42,
+				signer.public(),
+				sign_link(&signer, 42, BOB)
+			),
+			Error::<Test>::UnknownFidSigner
+		);
+		assert_eq!(TemplateModule::fid_of(ALICE), Some(42));
+	});
+}
+
+#[test]
+fn relinking_fid_moves_it_between_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::register_artist(RuntimeOrigin::signed(ALICE)));
+		link_fid(ALICE, 42);
+		assert_ok!(set_profile(ALICE, Some(42)));
+
+		link_fid(BOB, 42);
+		assert_eq!(TemplateModule::account_of_fid(42), Some(BOB));
+		assert_eq!(TemplateModule::fid_of(BOB), Some(42));
+		assert_eq!(TemplateModule::fid_of(ALICE), None);
+		// The old account's profile no longer claims the FID
+		assert_eq!(TemplateModule::artist_profile(ALICE).unwrap().fid, None);
+		assert_eq!(TemplateModule::fid_to_artist(42), None);
+
+		link_fid(BOB, 7);
+		assert_eq!(TemplateModule::account_of_fid(42), None);
+		assert_eq!(TemplateModule::fid_of(BOB), Some(7));
+	});
+}
+
+const CAST: [u8; 20] = [7; 20];
+/// FID that posted `CAST`.
+const CAST_FID: u64 = 3;
+
+fn gating_rule(cast_hash: [u8; 20]) -> GatingRule {
+	GatingRule { cast_hash, actions: EngagementActions { like: true, recast: true } }
+}
+
+fn attester() -> ed25519::Pair {
+	ed25519::Pair::from_seed(&[0xaa; 32])
+}
+
+/// Attestation by `signer` that `who` engaged with `cast_hash`, for their current nonce.
+fn attest(
+	signer: &ed25519::Pair,
+	who: AccountId,
+	cast_hash: [u8; 20],
+	expiry: u64,
+) -> EngagementAttestation<u64> {
+	let payload = TemplateModule::engagement_payload(
+		TemplateModule::fid_of(who).unwrap_or_default(),
+		&who,
+		&gating_rule(cast_hash),
+		expiry,
+		TemplateModule::engagement_nonce(who),
+	);
+	EngagementAttestation { attester: signer.public(), expiry, signature: signer.sign(&payload) }
+}
+
+#[test]
+fn set_collection_gating_is_creator_only() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		assert_noop!(
+			TemplateModule::set_collection_gating(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				Some(gating_rule(CAST))
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Some(gating_rule(CAST))
+		));
+		System::assert_last_event(
+			Event::CollectionGatingSet(collection_id, Some(gating_rule(CAST))).into(),
+		);
+		assert_eq!(TemplateModule::collection_gating(collection_id), Some(gating_rule(CAST)));
+
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			None
+		));
+		assert_eq!(TemplateModule::collection_gating(collection_id), None);
+
+		// The rule goes away with the collection
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Some(gating_rule(CAST))
+		));
+		assert_ok!(TemplateModule::delete_collection(RuntimeOrigin::signed(ALICE), collection_id));
+		assert_eq!(TemplateModule::collection_gating(collection_id), None);
+	});
+}
+
+#[test]
+fn gated_purchase_requires_valid_attestation() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Some(gating_rule(CAST))
+		));
+		let buy = |attestation| {
+			TemplateModule::mint_nft(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				attestation,
+			)
+		};
+
+		assert_noop!(buy(None), Error::<Test>::EngagementRequired);
+		assert_noop!(
+			buy(Some(attest(&attester(), BOB, CAST, 10))),
+			Error::<Test>::UntrustedAttester
+		);
+		Attesters::set(vec![attester().public()]);
+		assert_noop!(buy(Some(attest(&attester(), BOB, CAST, 10))), Error::<Test>::FidNotLinked);
+
+		link_fid(BOB, 42);
+		assert_noop!(
+			buy(Some(attest(&attester(), BOB, CAST, 0))),
+			Error::<Test>::AttestationExpired
+		);
+		// Signed for another cast, another account or by an untrusted key
+		assert_noop!(
+			buy(Some(attest(&attester(), BOB, [8; 20], 10))),
+			Error::<Test>::BadAttestation
+		);
+		assert_noop!(
+			buy(Some(attest(&attester(), CHARLIE, CAST, 10))),
+			Error::<Test>::BadAttestation
+		);
+		let mut forged = attest(&fid_signer(42), BOB, CAST, 10);
+		forged.attester = attester().public();
+		assert_noop!(buy(Some(forged)), Error::<Test>::BadAttestation);
+
+		assert_ok!(buy(Some(attest(&attester(), BOB, CAST, 10))));
+		assert_eq!(TemplateModule::nfts(collection_id, item_id).unwrap().owner, BOB);
+		assert_eq!(TemplateModule::engagement_nonce(BOB), 1);
+	});
+}
+
+#[test]
+fn engagement_attestation_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let (collection_id, item_id) = setup_auction(100);
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Some(gating_rule(CAST))
+		));
+		Attesters::set(vec![attester().public()]);
+		link_fid(BOB, 42);
+
+		let attestation = attest(&attester(), BOB, CAST, 50);
+		assert_noop!(
+			TemplateModule::bid(RuntimeOrigin::signed(BOB), collection_id, item_id, 200, None),
+			Error::<Test>::EngagementRequired
+		);
+		assert_ok!(TemplateModule::bid(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			200,
+			Some(attestation.clone())
+		));
+		assert_noop!(
+			TemplateModule::bid(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				300,
+				Some(attestation)
+			),
+			Error::<Test>::BadAttestation
+		);
+
+		assert_noop!(
+			TemplateModule::make_offer(
+				RuntimeOrigin::signed(BOB),
+				collection_id,
+				item_id,
+				300,
+				50,
+				None
+			),
+			Error::<Test>::EngagementRequired
+		);
+		assert_ok!(TemplateModule::make_offer(
+			RuntimeOrigin::signed(BOB),
+			collection_id,
+			item_id,
+			300,
+			50,
+			Some(attest(&attester(), BOB, CAST, 50))
+		));
+		assert_eq!(TemplateModule::engagement_nonce(BOB), 2);
+	});
+}
+
+/// Hub `reactionById` request for a reaction of FID 42 to `CAST`, answered with `body`.
+fn hub_request(reaction_type: &str, body: &str) -> PendingRequest {
+	PendingRequest {
+		method: "GET".into(),
+		uri: format!(
+			"{}/v1/reactionById?fid=42&reaction_type={reaction_type}\
+			 &target_fid={CAST_FID}&target_hash=0x{}",
+			offchain::DEFAULT_HUB_URL,
+			"07".repeat(20)
+		),
+		response: Some(body.as_bytes().to_vec()),
+		sent: true,
+		..Default::default()
+	}
+}
+
+/// Verdict on the pending check of `fid`, signed by `signer`.
+fn verdict(
+	signer: &ed25519::Pair,
+	fid: u64,
+	cast_hash: [u8; 20],
+	actions: EngagementActions,
+) -> Call<Test> {
+	let verdict = EngagementVerdict {
+		fid,
+		cast_hash,
+		requested_at: TemplateModule::pending_engagement_checks(fid, cast_hash)
+			.map(|check| check.requested_at)
+			.unwrap_or_default(),
+		actions,
+		attester: signer.public(),
+	};
+	let signature = signer.sign(&TemplateModule::engagement_verdict_payload(&verdict));
+	Call::submit_engagement_verdict { verdict, signature }
+}
+
+#[test]
+fn request_engagement_check_requires_linked_fid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::request_engagement_check(
+				RuntimeOrigin::signed(BOB),
+				42,
+				CAST_FID,
+				CAST
+			),
+			Error::<Test>::FidNotLinked
+		);
+		link_fid(BOB, 42);
+		assert_ok!(TemplateModule::request_engagement_check(
+			RuntimeOrigin::signed(BOB),
+			42,
+			CAST_FID,
+			CAST
+		));
+		System::assert_last_event(Event::EngagementCheckRequested(42, CAST).into());
+		assert_eq!(
+			TemplateModule::pending_engagement_checks(42, CAST),
+			Some(PendingEngagementCheck { requester: BOB, cast_fid: CAST_FID, requested_at: 1 })
+		);
+		assert_noop!(
+			TemplateModule::request_engagement_check(
+				RuntimeOrigin::signed(BOB),
+				42,
+				CAST_FID,
+				CAST
+			),
+			Error::<Test>::EngagementCheckPending
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_submits_hub_verdict() {
+	let mut ext = new_test_ext();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let keystore = MemoryKeystore::new();
+	let attester = keystore.ed25519_generate_new(KEY_TYPE, Some("//Attester")).unwrap();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt::new(Arc::new(keystore)));
+
+	ext.execute_with(|| {
+		link_fid(BOB, 42);
+		assert_ok!(TemplateModule::request_engagement_check(
+			RuntimeOrigin::signed(BOB),
+			42,
+			CAST_FID,
+			CAST
+		));
+
+		// Not an attester: the worker stays idle
+		TemplateModule::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		Attesters::set(vec![attester]);
+		let like = format!(
+			concat!(
+				r#"{{"data":{{"type":"MESSAGE_TYPE_REACTION_ADD","fid":42,"reactionBody":{{"#,
+				r#""type":"REACTION_TYPE_LIKE","targetCastId":{{"fid":{},"hash":"0x{}"}}}}}}}}"#,
+			),
+			CAST_FID,
+			"07".repeat(20)
+		);
+		{
+			let mut state = offchain_state.write();
+			state.expect_request(hub_request("REACTION_TYPE_LIKE", &like));
+			state.expect_request(hub_request(
+				"REACTION_TYPE_RECAST",
+				r#"{"errCode":"not_found","presentable":false,"name":"HubError","code":3}"#,
+			));
+		}
+		TemplateModule::offchain_worker(1);
+		// Within the retry window the check is not sent to the Hub again
+		TemplateModule::offchain_worker(2);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let call = Extrinsic::decode(&mut &*tx).unwrap().call;
+		let RuntimeCall::TemplateModule(call) = call else { panic!("unexpected call") };
+		let Call::submit_engagement_verdict { verdict, .. } = &call else {
+			panic!("unexpected call")
+		};
+		assert_eq!(verdict.actions, EngagementActions { like: true, recast: false });
+		assert_eq!(verdict.attester, attester);
+
+		assert_ok!(TemplateModule::validate_unsigned(TransactionSource::Local, &call));
+		assert_ok!(call.clone().dispatch_bypass_filter(RuntimeOrigin::none()));
+		System::assert_last_event(
+			Event::EngagementVerified(42, CAST, EngagementActions { like: true, recast: false })
+				.into(),
+		);
+		assert_eq!(TemplateModule::pending_engagement_checks(42, CAST), None);
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Stale.into()
+		);
+	});
+}
+
+#[test]
+fn engagement_verdict_must_be_signed_by_attester() {
+	new_test_ext().execute_with(|| {
+		Attesters::set(vec![attester().public()]);
+		link_fid(BOB, 42);
+		let like = EngagementActions { like: true, recast: false };
+		assert_noop!(
+			verdict(&attester(), 42, CAST, like).dispatch_bypass_filter(RuntimeOrigin::none()),
+			Error::<Test>::EngagementCheckNotFound
+		);
+		assert_ok!(TemplateModule::request_engagement_check(
+			RuntimeOrigin::signed(BOB),
+			42,
+			CAST_FID,
+			CAST
+		));
+
+		let untrusted = verdict(&fid_signer(42), 42, CAST, like);
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &untrusted),
+			InvalidTransaction::BadProof.into()
+		);
+		assert_noop!(
+			untrusted.dispatch_bypass_filter(RuntimeOrigin::signed(BOB)),
+			DispatchError::BadOrigin
+		);
+		let Call::submit_engagement_verdict { verdict: mut forged, signature } =
+			verdict(&attester(), 42, CAST, like)
+		else {
+			unreachable!()
+		};
+		forged.actions.recast = true;
+		assert_noop!(
+			TemplateModule::submit_engagement_verdict(RuntimeOrigin::none(), forged, signature),
+			Error::<Test>::BadAttestation
+		);
+	});
+}
+
+#[test]
+fn verified_engagement_satisfies_gating() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Some(gating_rule(CAST))
+		));
+		Attesters::set(vec![attester().public()]);
+		link_fid(BOB, 42);
+		let buy =
+			|| TemplateModule::mint_nft(RuntimeOrigin::signed(BOB), collection_id, item_id, None);
+
+		assert_ok!(TemplateModule::request_engagement_check(
+			RuntimeOrigin::signed(BOB),
+			42,
+			CAST_FID,
+			CAST
+		));
+		assert_ok!(verdict(&attester(), 42, CAST, EngagementActions { like: true, recast: false })
+			.dispatch_bypass_filter(RuntimeOrigin::none()));
+		// Liked but not recast
+		assert_noop!(buy(), Error::<Test>::EngagementRequired);
+
+		// A later verdict adds to the reactions verified before
+		assert_ok!(TemplateModule::request_engagement_check(
+			RuntimeOrigin::signed(BOB),
+			42,
+			CAST_FID,
+			CAST
+		));
+		assert_ok!(verdict(&attester(), 42, CAST, EngagementActions { like: false, recast: true })
+			.dispatch_bypass_filter(RuntimeOrigin::none()));
+		System::assert_last_event(
+			Event::EngagementVerified(42, CAST, EngagementActions { like: true, recast: true })
+				.into(),
+		);
+		assert_eq!(
+			TemplateModule::verified_engagements(42, CAST),
+			Some(EngagementActions { like: true, recast: true })
+		);
+		assert_ok!(buy());
+		assert_eq!(TemplateModule::nfts(collection_id, item_id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn engagement_checks_are_capped_per_account() {
+	new_test_ext().execute_with(|| {
+		Attesters::set(vec![attester().public()]);
+		link_fid(BOB, 42);
+		let request = |cast_hash| {
+			TemplateModule::request_engagement_check(
+				RuntimeOrigin::signed(BOB),
+				42,
+				CAST_FID,
+				cast_hash,
+			)
+		};
+		assert_ok!(request(CAST));
+		assert_ok!(request([8; 20]));
+		assert_noop!(request([9; 20]), Error::<Test>::TooManyEngagementChecks);
+		assert_eq!(TemplateModule::pending_engagement_check_count(BOB), 2);
+
+		// An answered check frees its slot
+		assert_ok!(verdict(&attester(), 42, CAST, EngagementActions::default())
+			.dispatch_bypass_filter(RuntimeOrigin::none()));
+		assert_eq!(TemplateModule::pending_engagement_check_count(BOB), 1);
+		assert_ok!(request([9; 20]));
+	});
+}
+
+#[test]
+fn expired_engagement_check_is_dropped() {
+	let mut ext = new_test_ext();
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let keystore = MemoryKeystore::new();
+	let attester = keystore.ed25519_generate_new(KEY_TYPE, Some("//Attester")).unwrap();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt::new(Arc::new(keystore)));
+
+	ext.execute_with(|| {
+		Attesters::set(vec![attester]);
+		link_fid(BOB, 42);
+		assert_ok!(TemplateModule::request_engagement_check(
+			RuntimeOrigin::signed(BOB),
+			42,
+			CAST_FID,
+			CAST
+		));
+
+		System::set_block_number(21);
+		assert_noop!(
+			TemplateModule::clear_expired_engagement_check(RuntimeOrigin::signed(ALICE), 42, CAST),
+			Error::<Test>::EngagementCheckNotExpired
+		);
+
+		// Past the timeout the worker no longer asks the Hub
+		System::set_block_number(22);
+		TemplateModule::offchain_worker(22);
+		assert!(pool_state.read().transactions.is_empty());
+
+		assert_ok!(TemplateModule::clear_expired_engagement_check(
+			RuntimeOrigin::signed(ALICE),
+			42,
+			CAST
+		));
+		System::assert_last_event(Event::EngagementCheckExpired(42, CAST).into());
+		assert_eq!(TemplateModule::pending_engagement_checks(42, CAST), None);
+		assert_eq!(TemplateModule::pending_engagement_check_count(BOB), 0);
+		assert_noop!(
+			TemplateModule::clear_expired_engagement_check(RuntimeOrigin::signed(ALICE), 42, CAST),
+			Error::<Test>::EngagementCheckNotFound
+		);
+	});
+}
+
+#[test]
+fn oracle_attestations_satisfy_gating() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Some(gating_rule(CAST))
+		));
+		let buy =
+			|| TemplateModule::mint_nft(RuntimeOrigin::signed(BOB), collection_id, item_id, None);
+		OracleEngagements::set(vec![(42, CAST, EngagementActions { like: false, recast: true })]);
+		// The oracle is only consulted for the FID linked to the buyer
+		assert_noop!(buy(), Error::<Test>::EngagementRequired);
+
+		link_fid(BOB, 42);
+		assert_noop!(buy(), Error::<Test>::EngagementRequired);
+
+		// Reactions verified on chain and attested by the oracle add up
+		assert_ok!(TemplateModule::submit_reaction(RuntimeOrigin::signed(BOB), reaction(42, 1)));
+		assert_ok!(buy());
+	});
+}
+
+fn pb_varint(out: &mut Vec<u8>, mut value: u64) {
+	while value >= 0x80 {
+		out.push(value as u8 | 0x80);
+		value >>= 7;
+	}
+	out.push(value as u8);
+}
+
+fn pb_uint(out: &mut Vec<u8>, field: u64, value: u64) {
+	pb_varint(out, field << 3);
+	pb_varint(out, value);
+}
+
+fn pb_bytes(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+	pb_varint(out, field << 3 | 2);
+	pb_varint(out, bytes.len() as u64);
+	out.extend_from_slice(bytes);
+}
+
+/// `MessageData` of a reaction by `fid` to a cast with `cast_hash`.
+fn reaction_data(message_type: u64, fid: u64, network: u64, reaction_type: u64) -> Vec<u8> {
+	let mut cast_id = vec![];
+	pb_uint(&mut cast_id, 1, 3);
+	pb_bytes(&mut cast_id, 2, &CAST);
+	let mut body = vec![];
+	pb_uint(&mut body, 1, reaction_type);
+	pb_bytes(&mut body, 2, &cast_id);
+	let mut data = vec![];
+	pb_uint(&mut data, 1, message_type);
+	pb_uint(&mut data, 2, fid);
+	pb_uint(&mut data, 3, 120_000_000);
+	pb_uint(&mut data, 4, network);
+	pb_bytes(&mut data, 7, &body);
+	data
+}
+
+/// A Farcaster `Message` carrying `data`, hashed and signed by `signer`.
+fn signed_message(signer: &ed25519::Pair, data: &[u8]) -> Vec<u8> {
+	let digest = blake3::hash(data);
+	let hash = &digest.as_bytes()[..20];
+	let mut message = vec![];
+	pb_bytes(&mut message, 1, data);
+	pb_bytes(&mut message, 2, hash);
+	pb_uint(&mut message, 3, 1);
+	pb_bytes(&mut message, 4, &signer.sign(hash).0);
+	pb_uint(&mut message, 5, 1);
+	pb_bytes(&mut message, 6, &signer.public().0);
+	message
+}
+
+fn reaction(fid: u64, reaction_type: u64) -> Vec<u8> {
+	signed_message(&fid_signer(fid), &reaction_data(3, fid, 1, reaction_type))
+}
+
+#[test]
+fn signed_reactions_satisfy_gating() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 50);
+		assert_ok!(TemplateModule::set_collection_gating(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			Some(gating_rule(CAST))
+		));
+		link_fid(BOB, 42);
+		let buy =
+			|| TemplateModule::mint_nft(RuntimeOrigin::signed(BOB), collection_id, item_id, None);
+
+		// Anyone can relay the messages
+		assert_ok!(TemplateModule::submit_reaction(
+			RuntimeOrigin::signed(CHARLIE),
+			reaction(42, 1)
+		));
+		System::assert_last_event(
+			Event::EngagementVerified(42, CAST, EngagementActions { like: true, recast: false })
+				.into(),
+		);
+		assert_noop!(buy(), Error::<Test>::EngagementRequired);
+
+		assert_ok!(TemplateModule::submit_reaction(
+			RuntimeOrigin::signed(CHARLIE),
+			reaction(42, 2)
+		));
+		assert_eq!(
+			TemplateModule::verified_engagements(42, CAST),
+			Some(EngagementActions { like: true, recast: true })
+		);
+		assert_ok!(buy());
+	});
+}
+
+#[test]
+fn submit_reaction_rejects_invalid_messages() {
+	new_test_ext().execute_with(|| {
+		let submit = |message| TemplateModule::submit_reaction(RuntimeOrigin::signed(BOB), message);
+
+		// Signer not registered for the FID
+		assert_noop!(submit(reaction(42, 1)), Error::<Test>::UnknownFidSigner);
+		add_signer(42, fid_signer(42).public());
+		assert_noop!(
+			submit(signed_message(&fid_signer(7), &reaction_data(3, 42, 1, 1))),
+			Error::<Test>::UnknownFidSigner
+		);
+
+		let mut tampered = reaction(42, 1);
+		let last = tampered.len() - 1;
+		tampered[last] ^= 1;
+		assert_noop!(submit(tampered), Error::<Test>::InvalidFarcasterMessage);
+		let mut wrong_hash = reaction(42, 1);
+		// Flip a byte of the data, which comes first
+		wrong_hash[4] ^= 1;
+		assert_noop!(submit(wrong_hash), Error::<Test>::InvalidFarcasterMessage);
+		assert_noop!(submit(b"not a message".to_vec()), Error::<Test>::InvalidFarcasterMessage);
+		assert_noop!(submit(vec![0; 1025]), Error::<Test>::InvalidFarcasterMessage);
+
+		// A cast, and a reaction of an unknown type
+		let signer = fid_signer(42);
+		assert_noop!(
+			submit(signed_message(&signer, &reaction_data(1, 42, 1, 1))),
+			Error::<Test>::NotAReaction
+		);
+		assert_noop!(
+			submit(signed_message(&signer, &reaction_data(3, 42, 1, 9))),
+			Error::<Test>::NotAReaction
+		);
+		assert_noop!(
+			submit(signed_message(&signer, &reaction_data(3, 42, 2, 1))),
+			Error::<Test>::WrongFarcasterNetwork
+		);
+		assert_ok!(submit(reaction(42, 1)));
+	});
+}
+
+#[test]
+fn try_state_catches_corrupted_collections() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 100);
+		assert_ok!(TemplateModule::do_try_state());
+
+		// Item stored but not counted by the collection
+		Collections::<Test>::mutate(collection_id, |c| c.as_mut().unwrap().items = 0);
+		assert!(TemplateModule::do_try_state().is_err());
+		Collections::<Test>::mutate(collection_id, |c| c.as_mut().unwrap().items = 1);
+
+		// More items than the max supply
+		Collections::<Test>::mutate(collection_id, |c| c.as_mut().unwrap().max_supply = Some(0));
+		assert!(TemplateModule::do_try_state().is_err());
+		Collections::<Test>::mutate(collection_id, |c| c.as_mut().unwrap().max_supply = None);
+
+		// Item id not below the counter
+		NextItemId::<Test>::insert(collection_id, item_id);
+		assert!(TemplateModule::do_try_state().is_err());
+		NextItemId::<Test>::insert(collection_id, item_id + 1);
+
+		// Creator no longer an artist
+		Artists::<Test>::remove(ALICE);
+		assert!(TemplateModule::do_try_state().is_err());
+		Artists::<Test>::insert(ALICE, ArtistStatus::Verified);
+	});
+}
+
+#[test]
+fn try_state_catches_stale_indexes() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		let item_id = create_nft(ALICE, collection_id, 100);
+
+		OwnedNfts::<Test>::remove(ALICE, (collection_id, item_id));
+		assert!(TemplateModule::do_try_state().is_err());
+		OwnedNfts::<Test>::insert(BOB, (collection_id, item_id), ());
+		assert!(TemplateModule::do_try_state().is_err());
+		OwnedNfts::<Test>::remove(BOB, (collection_id, item_id));
+		OwnedNfts::<Test>::insert(ALICE, (collection_id, item_id), ());
+
+		link_fid(BOB, 42);
+		AccountOfFid::<Test>::remove(42);
+		assert!(TemplateModule::do_try_state().is_err());
+		AccountOfFid::<Test>::insert(42, BOB);
+		assert_ok!(TemplateModule::do_try_state());
+	});
+}
+
+#[test]
+fn migration_to_v1_translates_the_v0_layout() {
+	use crate::migrations::{
+		v1::{v0, MigrateV0ToV1},
+		v2::MigrateV1ToV2,
+	};
+	use frame_support::{
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::constants::RocksDbWeight,
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<TemplateModule>();
+		ArtistBond::set(500);
+		// An artist without the funds for the bond
+		let unfunded = 4;
+		v0::Artists::<Test>::insert(ALICE, true);
+		v0::Artists::<Test>::insert(unfunded, true);
+		v0::Collections::<Test>::insert(
+			0,
+			v0::Collection {
+				creator: ALICE,
+				metadata: b"collection".to_vec().try_into().unwrap(),
+				nfts: vec![0, 1].try_into().unwrap(),
+			},
+		);
+		let nft = |owner, is_sold| crate::NFT {
+			owner,
+			metadata: b"ipfs://item".to_vec().try_into().unwrap(),
+			is_sold,
+			price: 100,
+		};
+		crate::Nfts::<Test>::insert(0, 0, nft(ALICE, false));
+		crate::Nfts::<Test>::insert(0, 1, nft(BOB, true));
+		NextItemId::<Test>::insert(0, 2);
+
+		// Chains at version 0 run every later migration too, as the runtime lists them
+		type Migrations = (MigrateV0ToV1<Test>, MigrateV1ToV2<Test>);
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(Migrations::try_on_runtime_upgrade(true));
+		#[cfg(not(feature = "try-runtime"))]
+		Migrations::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 2);
+		assert_eq!(TemplateModule::artists(ALICE), Some(ArtistStatus::Verified));
+		assert_eq!(Balances::reserved_balance(ALICE), 500);
+		assert_eq!(TemplateModule::artists(unfunded), Some(ArtistStatus::Verified));
+		assert_eq!(Balances::reserved_balance(unfunded), 0);
+		assert_eq!(TemplateModule::artist_collection_count(ALICE), 1);
+		let collection = TemplateModule::collections(0).unwrap();
+		assert_eq!(collection.royalty, Perbill::zero());
+		assert_eq!(collection.royalty_recipient, ALICE);
+		assert!(!collection.is_frozen);
+		assert_eq!(collection.items, 2);
+		assert!(OwnedNfts::<Test>::contains_key(ALICE, (0, 0)));
+		assert!(OwnedNfts::<Test>::contains_key(BOB, (0, 1)));
+		assert_eq!(Listings::<Test>::get(0, 0), Some(100));
+		assert_eq!(Listings::<Test>::get(0, 1), None);
+
+		// Migrated items trade like new ones
+		assert_ok!(TemplateModule::mint_nft(RuntimeOrigin::signed(CHARLIE), 0, 0, None));
+		assert_eq!(TemplateModule::nfts(0, 0).unwrap().owner, CHARLIE);
+
+		// The version guard keeps the migration from running twice
+		assert_eq!(MigrateV0ToV1::<Test>::on_runtime_upgrade(), RocksDbWeight::get().reads(1));
+	});
+}
+
+#[test]
+fn migration_to_v2_counts_the_items_of_collections() {
+	use crate::migrations::v2::{v1, MigrateV1ToV2};
+	use frame_support::{
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::constants::RocksDbWeight,
+	};
+
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		create_nft(ALICE, collection_id, 100);
+		create_nft(ALICE, collection_id, 100);
+		let collection = Collections::<Test>::get(collection_id).unwrap();
+		StorageVersion::new(1).put::<TemplateModule>();
+		v1::Collections::<Test>::insert(
+			collection_id,
+			v1::Collection {
+				creator: collection.creator,
+				metadata: collection.metadata,
+				nfts: vec![0, 1].try_into().unwrap(),
+				royalty: Perbill::from_percent(5),
+				royalty_recipient: BOB,
+				is_frozen: false,
+			},
+		);
+
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(MigrateV1ToV2::<Test>::try_on_runtime_upgrade(true));
+		#[cfg(not(feature = "try-runtime"))]
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::on_chain_storage_version(), 2);
+		let collection = TemplateModule::collections(collection_id).unwrap();
+		assert_eq!(collection.items, 2);
+		assert_eq!(collection.max_supply, None);
+		assert_eq!(collection.royalty, Perbill::from_percent(5));
+		assert_eq!(collection.royalty_recipient, BOB);
+
+		// The version guard keeps the migration from running twice
+		assert_eq!(MigrateV1ToV2::<Test>::on_runtime_upgrade(), RocksDbWeight::get().reads(1));
+	});
+}
+
+#[test]
+fn collections_grow_past_256_items() {
+	new_test_ext().execute_with(|| {
+		let collection_id = setup_collection(ALICE);
+		for _ in 0..300 {
+			create_nft(ALICE, collection_id, 10);
+		}
+
+		assert_eq!(TemplateModule::collections(collection_id).unwrap().items, 300);
+		assert!(TemplateModule::nfts(collection_id, 299).is_some());
+	});
+}
+
+#[test]
+fn max_supply_caps_the_items_of_a_collection() {
+	new_test_ext().execute_with(|| {
+		register_verified_artist(ALICE);
+		assert_ok!(TemplateModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			b"collection".to_vec(),
+			Perbill::zero(),
+			None,
+			Some(2)
+		));
+		assert_eq!(TemplateModule::collections(0).unwrap().max_supply, Some(2));
+
+		// Only the creator's mints count against the cap
+		assert_noop!(
+			TemplateModule::create_nft(RuntimeOrigin::signed(BOB), 0, b"x".to_vec(), 10),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_eq!(TemplateModule::collections(0).unwrap().items, 0);
+
+		let item_id = create_nft(ALICE, 0, 10);
+		create_nft(ALICE, 0, 10);
+
+		assert_noop!(
+			TemplateModule::create_nft(RuntimeOrigin::signed(ALICE), 0, b"three".to_vec(), 10),
+			Error::<Test>::MaxSupplyReached
+		);
+		assert_noop!(
+			TemplateModule::batch_create_nfts(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				vec![(b"three".to_vec(), 10)]
+			),
+			Error::<Test>::MaxSupplyReached
+		);
+
+		// Burning frees a slot
+		assert_ok!(TemplateModule::burn_nft(RuntimeOrigin::signed(ALICE), 0, item_id));
+		create_nft(ALICE, 0, 10);
+		assert_eq!(TemplateModule::collections(0).unwrap().items, 2);
+	});
+}
+
+#[test]
+fn nonfungibles_inspect_serves_attributes_from_metadata() {
+	use frame_support::traits::tokens::nonfungibles_v2::{Inspect, InspectEnumerable};
+
+	new_test_ext().execute_with(|| {
+		register_verified_artist(ALICE);
+		assert_ok!(TemplateModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			br#"{"title":"Dusk"}"#.to_vec(),
+			Perbill::zero(),
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_nft(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			br#"{"title":"Sunset","edition":3}"#.to_vec(),
+			10
+		));
+		create_nft(ALICE, 0, 10);
+		assert_ok!(TemplateModule::mint_nft(RuntimeOrigin::signed(BOB), 0, 1, None));
+
+		assert_eq!(<TemplateModule as Inspect<AccountId>>::owner(&0, &1), Some(BOB));
+		assert_eq!(TemplateModule::collection_owner(&0), Some(ALICE));
+		assert_eq!(TemplateModule::attribute(&0, &0, b"title"), Some(b"Sunset".to_vec()));
+		assert_eq!(TemplateModule::attribute(&0, &0, b"edition"), Some(b"3".to_vec()));
+		assert_eq!(TemplateModule::attribute(&0, &0, b"image"), None);
+		// Metadata that is not a JSON object has no attributes
+		assert_eq!(TemplateModule::attribute(&0, &1, b"title"), None);
+		assert_eq!(TemplateModule::collection_attribute(&0, b"title"), Some(b"Dusk".to_vec()));
+		assert!(<TemplateModule as Inspect<AccountId>>::can_transfer(&0, &0));
+		assert!(!<TemplateModule as Inspect<AccountId>>::can_transfer(&0, &2));
+
+		assert_eq!(
+			<TemplateModule as InspectEnumerable<AccountId>>::collections().collect::<Vec<_>>(),
+			vec![0]
+		);
+		let mut items: Vec<_> =
+			<TemplateModule as InspectEnumerable<AccountId>>::items(&0).collect();
+		items.sort();
+		assert_eq!(items, vec![0, 1]);
+		assert_eq!(TemplateModule::owned(&BOB).collect::<Vec<_>>(), vec![(0, 1)]);
+		assert_eq!(TemplateModule::owned_in_collection(&0, &ALICE).collect::<Vec<_>>(), vec![0]);
+		assert_eq!(TemplateModule::owned_in_collection(&1, &ALICE).count(), 0);
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn nonfungibles_create_mint_transfer_and_burn() {
+	use crate::CollectionConfig;
+	use frame_support::traits::tokens::nonfungibles_v2::{Create, Inspect, Mutate, Transfer};
+
+	new_test_ext().execute_with(|| {
+		let config = CollectionConfig {
+			royalty: Perbill::zero(),
+			royalty_recipient: None,
+			max_supply: Some(2),
+		};
+		assert_noop!(
+			<TemplateModule as Create<_, _>>::create_collection(&ALICE, &ALICE, &config),
+			Error::<Test>::NotRegisteredArtist
+		);
+		register_verified_artist(ALICE);
+		assert_eq!(
+			<TemplateModule as Create<_, _>>::create_collection(&ALICE, &BOB, &config),
+			Ok(0)
+		);
+		assert_noop!(
+			TemplateModule::create_collection_with_id(0, &ALICE, &ALICE, &config),
+			Error::<Test>::CollectionIdInUse
+		);
+		assert_ok!(TemplateModule::create_collection_with_id(5, &ALICE, &ALICE, &config));
+		assert_eq!(TemplateModule::next_collection_id(), 6);
+
+		// Items can be minted at any free id, straight to their owner
+		set_deposits(0, 20, 0);
+		assert_ok!(TemplateModule::mint_into(&0, &7, &BOB, &(), true));
+		System::assert_last_event(Event::NFTMinted(0, 7, BOB).into());
+		assert_eq!(Balances::reserved_balance(BOB), 20);
+		assert_eq!(TemplateModule::next_item_id(0), 8);
+		assert_eq!(Listings::<Test>::get(0, 7), None);
+		assert_noop!(
+			TemplateModule::mint_into(&0, &7, &ALICE, &(), false),
+			Error::<Test>::ItemIdInUse
+		);
+		assert_ok!(TemplateModule::mint_into(&0, &1, &ALICE, &(), false));
+		assert_noop!(
+			TemplateModule::mint_into(&0, &2, &ALICE, &(), false),
+			Error::<Test>::MaxSupplyReached
+		);
+
+		assert_ok!(TemplateModule::set_item_metadata(None, &0, &7, br#"{"title":"Granted"}"#));
+		assert_eq!(TemplateModule::attribute(&0, &7, b"title"), Some(b"Granted".to_vec()));
+		assert_noop!(
+			TemplateModule::set_item_metadata(Some(&ALICE), &0, &7, b"x"),
+			Error::<Test>::NotNFTOwner
+		);
+
+		assert_ok!(<TemplateModule as Transfer<AccountId>>::transfer(&0, &7, &CHARLIE));
+		System::assert_last_event(Event::NFTTransferred(0, 7, BOB, CHARLIE).into());
+		assert_eq!(<TemplateModule as Inspect<AccountId>>::owner(&0, &7), Some(CHARLIE));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 20);
+		assert!(OwnedNfts::<Test>::contains_key(CHARLIE, (0, 7)));
+
+		assert_noop!(TemplateModule::burn(&0, &1, Some(&BOB)), Error::<Test>::NotNFTOwner);
+		assert_ok!(TemplateModule::burn(&0, &1, None));
+		assert!(TemplateModule::nfts(0, 1).is_none());
+		assert_eq!(TemplateModule::collections(0).unwrap().items, 1);
+	});
+}
+
+#[test]
+fn nonfungibles_follow_the_marketplace_restrictions() {
+	use frame_support::traits::tokens::nonfungibles_v2::{Mutate, Transfer};
+
 	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(Something::<Test>::get().map(|v| v.block_number), Some(42));
+		let collection_id = setup_collection(ALICE);
+		let auctioned = create_nft(ALICE, collection_id, 1_000);
+		let sold = create_nft(ALICE, collection_id, 1_000);
+		assert_ok!(TemplateModule::delist_nft(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			auctioned
+		));
+		assert_ok!(TemplateModule::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			collection_id,
+			auctioned,
+			100,
+			100
+		));
+		assert_noop!(
+			<TemplateModule as Transfer<AccountId>>::transfer(&collection_id, &auctioned, &BOB),
+			Error::<Test>::NftInAuction
+		);
+
+		// Sold items cannot be burned, even without an owner check
+		assert_ok!(TemplateModule::mint_nft(RuntimeOrigin::signed(BOB), collection_id, sold, None));
+		assert_noop!(
+			TemplateModule::burn(&collection_id, &sold, None),
+			Error::<Test>::NFTAlreadySold
+		);
+
+		assert_ok!(TemplateModule::freeze_collection(RuntimeOrigin::signed(ALICE), collection_id));
+		assert_noop!(
+			TemplateModule::mint_into(&collection_id, &9, &ALICE, &(), false),
+			Error::<Test>::CollectionFrozen
+		);
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn nonfungibles_destroy_checks_witness_and_owner() {
+	use frame_support::traits::tokens::nonfungibles_v2::Destroy;
+
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		let collection_id = setup_collection(ALICE);
+		create_nft(ALICE, collection_id, 10);
+		create_nft(ALICE, collection_id, 10);
+
+		assert_eq!(TemplateModule::get_destroy_witness(&collection_id), Some(2));
+		assert_eq!(TemplateModule::get_destroy_witness(&9), None);
+		assert_noop!(TemplateModule::destroy(collection_id, 1, None), Error::<Test>::BadWitness);
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			TemplateModule::destroy(collection_id, 2, Some(BOB)),
+			Error::<Test>::NotCollectionOwner
 		);
+		assert_eq!(TemplateModule::destroy(collection_id, 2, Some(ALICE)), Ok(2));
+		assert!(TemplateModule::collections(collection_id).is_none());
+		assert_eq!(TemplateModule::artist_collection_count(ALICE), 0);
 	});
 }
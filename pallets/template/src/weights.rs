@@ -1,20 +1,13 @@
+//! Weights for pallet_nft_farcaster_frames
 
-//! Autogenerated weights for pallet_template
-//!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `_`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-template
+// Command:
+// ./target/release/parachain-template-node
 // benchmark
 // pallet
 // --chain
 // dev
 // --pallet
-// pallet_template
+// pallet_parachain_template
 // --extrinsic
 // *
 // --steps=50
@@ -22,8 +15,6 @@
 // --wasm-execution=compiled
 // --output
 // pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -32,59 +23,654 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for pallet_nft_farcaster_frames.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn register_artist() -> Weight;
+	fn deregister_artist() -> Weight;
+	fn approve_artist() -> Weight;
+	fn revoke_artist() -> Weight;
+	fn create_collection(m: u32, ) -> Weight;
+	fn update_collection_metadata(m: u32, ) -> Weight;
+	fn freeze_collection() -> Weight;
+	fn unfreeze_collection() -> Weight;
+	fn set_collection_royalty() -> Weight;
+	fn set_collection_gating() -> Weight;
+	fn destroy_collection(n: u32, ) -> Weight;
+	fn create_nft(m: u32, ) -> Weight;
+	fn batch_create_nfts(n: u32, ) -> Weight;
+	fn transfer_nft() -> Weight;
+	fn burn_nft() -> Weight;
+	fn mint_nft() -> Weight;
+	fn list_nft() -> Weight;
+	fn update_price() -> Weight;
+	fn delist_nft() -> Weight;
+	fn update_nft_metadata(m: u32, ) -> Weight;
+	fn lock_nft_metadata() -> Weight;
+	fn withdraw_fees() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn settle_auctions(n: u32, ) -> Weight;
+	fn make_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn clear_expired_offer() -> Weight;
+	fn set_profile() -> Weight;
+	fn clear_profile() -> Weight;
+	fn link_fid() -> Weight;
+	fn unlink_fid() -> Weight;
+	fn request_engagement_check() -> Weight;
+	fn submit_reaction() -> Weight;
+	fn submit_engagement_verdict() -> Weight;
+	fn clear_expired_engagement_check() -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Weights for pallet_nft_farcaster_frames using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	/// Storage: TemplatePallet Artists (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: Balances Holds (r:1 w:1)
+	fn register_artist() -> Weight {
+		Weight::from_parts(52_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplatePallet Artists (r:1 w:1)
+	/// Storage: TemplatePallet ArtistCollectionCount (r:1 w:0)
+	/// Storage: TemplatePallet ArtistProfiles (r:1 w:1)
+	/// Storage: TemplatePallet FidToArtist (r:0 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn deregister_artist() -> Weight {
+		Weight::from_parts(48_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplatePallet Artists (r:1 w:1)
+	fn approve_artist() -> Weight {
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplatePallet Artists (r:1 w:1)
+	fn revoke_artist() -> Weight {
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplatePallet Artists (r:1 w:0)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplatePallet NextCollectionId (r:1 w:1)
+	/// Storage: TemplatePallet CollectionDeposits (r:0 w:1)
+	/// Storage: TemplatePallet Collections (r:0 w:1)
+	/// Storage: TemplatePallet ArtistCollectionCount (r:1 w:1)
+	/// The range of component `m` is `[0, 256]`.
+	fn create_collection(m: u32, ) -> Weight {
+		Weight::from_parts(47_000_000, 4764)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	/// Storage: TemplatePallet CollectionDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `m` is `[0, 256]`.
+	fn update_collection_metadata(m: u32, ) -> Weight {
+		Weight::from_parts(44_000_000, 4859)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	fn freeze_collection() -> Weight {
+		Weight::from_parts(16_000_000, 4859)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	fn unfreeze_collection() -> Weight {
+		Weight::from_parts(16_000_000, 4859)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	fn set_collection_royalty() -> Weight {
+		Weight::from_parts(16_000_000, 4859)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:0)
+	/// Storage: TemplatePallet CollectionGating (r:0 w:1)
+	fn set_collection_gating() -> Weight {
+		Weight::from_parts(15_000_000, 4859)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	/// Storage: TemplatePallet ForeignItems (r:1 w:1)
+	/// Storage: TemplatePallet Auctions (r:1 w:0)
+	/// Storage: TemplatePallet Nfts (r:21 w:20)
+	/// Storage: TemplatePallet ItemDeposits (r:20 w:20)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:20)
+	/// Storage: TemplatePallet Listings (r:0 w:20)
+	/// Storage: TemplatePallet MetadataLocked (r:0 w:20)
+	/// Storage: TemplatePallet Offers (r:420 w:400)
+	/// Storage: TemplatePallet OfferCount (r:0 w:20)
+	/// Storage: Balances Holds (r:401 w:401)
+	/// Storage: System Account (r:401 w:401)
+	/// Storage: TemplatePallet ArtistCollectionCount (r:1 w:1)
+	/// Storage: TemplatePallet CollectionDeposits (r:1 w:1)
+	/// Storage: TemplatePallet NextItemId (r:0 w:1)
+	/// Storage: TemplatePallet CollectionGating (r:0 w:1)
+	/// The range of component `n` is `[0, 20]`.
+	fn destroy_collection(n: u32, ) -> Weight {
+		Weight::from_parts(58_000_000, 4859)
+			.saturating_add(Weight::from_parts(461_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((63_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((66_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 178182).saturating_mul(n.into()))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplatePallet NextItemId (r:1 w:1)
+	/// Storage: TemplatePallet ItemDeposits (r:0 w:1)
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:1)
+	/// Storage: TemplatePallet Listings (r:0 w:1)
+	/// The range of component `m` is `[0, 256]`.
+	fn create_nft(m: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 4859)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: TemplatePallet NextItemId (r:1 w:1)
+	/// Storage: TemplatePallet ItemDeposits (r:0 w:50)
+	/// Storage: TemplatePallet Nfts (r:50 w:50)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:50)
+	/// Storage: TemplatePallet Listings (r:0 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn batch_create_nfts(n: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 4859)
+			.saturating_add(Weight::from_parts(32_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2842).saturating_mul(n.into()))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet Auctions (r:1 w:0)
+	/// Storage: TemplatePallet ItemDeposits (r:1 w:0)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:2)
+	/// Storage: TemplatePallet Listings (r:0 w:1)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	fn transfer_nft() -> Weight {
+		Weight::from_parts(68_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet Auctions (r:1 w:0)
+	/// Storage: TemplatePallet ItemDeposits (r:1 w:1)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:1)
+	/// Storage: TemplatePallet Listings (r:0 w:1)
+	/// Storage: TemplatePallet Collections (r:1 w:1)
+	/// Storage: TemplatePallet MetadataLocked (r:0 w:1)
+	/// Storage: TemplatePallet Offers (r:21 w:20)
+	/// Storage: TemplatePallet OfferCount (r:0 w:1)
+	/// Storage: Balances Holds (r:21 w:21)
+	/// Storage: System Account (r:21 w:21)
+	fn burn_nft() -> Weight {
+		Weight::from_parts(498_000_000, 180199)
+			.saturating_add(T::DbWeight::get().reads(67_u64))
+			.saturating_add(T::DbWeight::get().writes(69_u64))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet Listings (r:1 w:1)
+	/// Storage: TemplatePallet CollectionGating (r:1 w:0)
+	/// Storage: TemplatePallet FidOf (r:1 w:0)
+	/// Storage: TemplatePallet EngagementNonce (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: TemplatePallet Collections (r:1 w:0)
+	/// Storage: TemplatePallet ItemDeposits (r:1 w:0)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:2)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:4 w:4)
+	fn mint_nft() -> Weight {
+		Weight::from_parts(168_000_000, 11376)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet Auctions (r:1 w:0)
+	/// Storage: TemplatePallet Listings (r:1 w:1)
+	fn list_nft() -> Weight {
+		Weight::from_parts(21_000_000, 3850)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet Listings (r:1 w:1)
+	fn update_price() -> Weight {
+		Weight::from_parts(19_000_000, 3850)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:0)
+	/// Storage: TemplatePallet Listings (r:1 w:1)
+	fn delist_nft() -> Weight {
+		Weight::from_parts(17_000_000, 3850)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
+	/// Storage: TemplatePallet Collections (r:1 w:0)
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet MetadataLocked (r:1 w:0)
+	/// Storage: TemplatePallet ItemDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `m` is `[0, 256]`.
+	fn update_nft_metadata(m: u32, ) -> Weight {
+		Weight::from_parts(47_000_000, 4859)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplatePallet Collections (r:1 w:0)
+	/// Storage: TemplatePallet Nfts (r:1 w:0)
+	/// Storage: TemplatePallet MetadataLocked (r:1 w:1)
+	fn lock_nft_metadata() -> Weight {
+		Weight::from_parts(20_000_000, 4859)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: System Account (r:2 w:2)
+	fn withdraw_fees() -> Weight {
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:0)
+	/// Storage: TemplatePallet Auctions (r:1 w:1)
+	/// Storage: TemplatePallet Listings (r:1 w:0)
+	/// Storage: TemplatePallet AuctionsEndingAt (r:1 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_parts(30_000_000, 5141)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplatePallet Auctions (r:1 w:1)
+	/// Storage: TemplatePallet CollectionGating (r:1 w:0)
+	/// Storage: TemplatePallet FidOf (r:1 w:0)
+	/// Storage: TemplatePallet EngagementNonce (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: TemplatePallet AuctionsEndingAt (r:2 w:2)
+	/// Storage: Balances Holds (r:2 w:2)
+	/// Storage: System Account (r:1 w:1)
+	fn bid() -> Weight {
+		Weight::from_parts(135_000_000, 8762)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: TemplatePallet Auctions (r:1 w:1)
+	/// Storage: TemplatePallet AuctionsEndingAt (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		Weight::from_parts(24_000_000, 5141)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: TemplatePallet AuctionsEndingAt (r:1 w:1)
+	/// Storage: TemplatePallet Auctions (r:50 w:50)
+	/// Storage: Balances Holds (r:50 w:50)
+	/// Storage: System Account (r:150 w:150)
+	/// Storage: TemplatePallet Nfts (r:50 w:50)
+	/// Storage: TemplatePallet Collections (r:50 w:50)
+	/// Storage: TemplatePallet ItemDeposits (r:50 w:0)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:100)
+	/// The range of component `n` is `[0, 50]`.
+	fn settle_auctions(n: u32, ) -> Weight {
 		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(Weight::from_parts(98_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5141).saturating_mul(n.into()))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:0)
+	/// Storage: TemplatePallet CollectionGating (r:1 w:0)
+	/// Storage: TemplatePallet FidOf (r:1 w:0)
+	/// Storage: TemplatePallet EngagementNonce (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: TemplatePallet Offers (r:1 w:1)
+	/// Storage: TemplatePallet OfferCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn make_offer() -> Weight {
+		Weight::from_parts(116_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: TemplatePallet Offers (r:1 w:1)
+	/// Storage: TemplatePallet OfferCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(44_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplatePallet Nfts (r:1 w:1)
+	/// Storage: TemplatePallet Auctions (r:1 w:0)
+	/// Storage: TemplatePallet Offers (r:1 w:1)
+	/// Storage: TemplatePallet OfferCount (r:1 w:1)
+	/// Storage: TemplatePallet Collections (r:1 w:0)
+	/// Storage: TemplatePallet ItemDeposits (r:1 w:0)
+	/// Storage: TemplatePallet OwnedNfts (r:0 w:2)
+	/// Storage: TemplatePallet Listings (r:0 w:1)
+	/// Storage: Balances Holds (r:3 w:3)
+	/// Storage: System Account (r:4 w:4)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(132_000_000, 11376)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: TemplatePallet Offers (r:1 w:1)
+	/// Storage: TemplatePallet OfferCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn clear_expired_offer() -> Weight {
+		Weight::from_parts(45_000_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplatePallet Artists (r:1 w:0)
+	/// Storage: TemplatePallet FidOf (r:1 w:0)
+	/// Storage: TemplatePallet ArtistProfiles (r:1 w:1)
+	/// Storage: TemplatePallet FidToArtist (r:0 w:2)
+	fn set_profile() -> Weight {
+		Weight::from_parts(34_000_000, 2928)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplatePallet ArtistProfiles (r:1 w:1)
+	/// Storage: TemplatePallet FidToArtist (r:0 w:1)
+	fn clear_profile() -> Weight {
+		Weight::from_parts(24_000_000, 2928)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: FarcasterRegistry FidSigners (r:1 w:0)
+	/// Storage: TemplatePallet FidLinkNonce (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: TemplatePallet FidOf (r:2 w:2)
+	/// Storage: TemplatePallet AccountOfFid (r:2 w:2)
+	/// Storage: TemplatePallet ArtistProfiles (r:2 w:2)
+	/// Storage: TemplatePallet FidToArtist (r:0 w:2)
+	fn link_fid() -> Weight {
+		Weight::from_parts(98_000_000, 5856)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: TemplatePallet FidOf (r:1 w:1)
+	/// Storage: TemplatePallet AccountOfFid (r:0 w:1)
+	/// Storage: TemplatePallet ArtistProfiles (r:1 w:1)
+	/// Storage: TemplatePallet FidToArtist (r:0 w:1)
+	fn unlink_fid() -> Weight {
+		Weight::from_parts(30_000_000, 2928)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: TemplatePallet FidOf (r:1 w:0)
+	/// Storage: TemplatePallet PendingEngagementChecks (r:1 w:1)
+	/// Storage: TemplatePallet PendingEngagementCheckCount (r:1 w:1)
+	fn request_engagement_check() -> Weight {
+		Weight::from_parts(21_000_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: FarcasterRegistry FidSigners (r:1 w:0)
+	/// Storage: TemplatePallet VerifiedEngagements (r:1 w:1)
+	fn submit_reaction() -> Weight {
+		Weight::from_parts(76_000_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: TemplatePallet PendingEngagementChecks (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: TemplatePallet PendingEngagementCheckCount (r:1 w:1)
+	/// Storage: TemplatePallet VerifiedEngagements (r:1 w:1)
+	fn submit_engagement_verdict() -> Weight {
+		Weight::from_parts(78_000_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: TemplatePallet PendingEngagementChecks (r:1 w:1)
+	/// Storage: TemplatePallet PendingEngagementCheckCount (r:1 w:1)
+	fn clear_expired_engagement_check() -> Weight {
+		Weight::from_parts(20_000_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+	fn register_artist() -> Weight {
+		Weight::from_parts(52_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn deregister_artist() -> Weight {
+		Weight::from_parts(48_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn approve_artist() -> Weight {
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn revoke_artist() -> Weight {
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn create_collection(m: u32, ) -> Weight {
+		Weight::from_parts(47_000_000, 4764)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn update_collection_metadata(m: u32, ) -> Weight {
+		Weight::from_parts(44_000_000, 4859)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn freeze_collection() -> Weight {
+		Weight::from_parts(16_000_000, 4859)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn unfreeze_collection() -> Weight {
+		Weight::from_parts(16_000_000, 4859)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_collection_royalty() -> Weight {
+		Weight::from_parts(16_000_000, 4859)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_collection_gating() -> Weight {
+		Weight::from_parts(15_000_000, 4859)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
+	fn destroy_collection(n: u32, ) -> Weight {
+		Weight::from_parts(58_000_000, 4859)
+			.saturating_add(Weight::from_parts(461_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((63_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((66_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 178182).saturating_mul(n.into()))
+	}
+	fn create_nft(m: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 4859)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn batch_create_nfts(n: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 4859)
+			.saturating_add(Weight::from_parts(32_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2842).saturating_mul(n.into()))
+	}
+	fn transfer_nft() -> Weight {
+		Weight::from_parts(68_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn burn_nft() -> Weight {
+		Weight::from_parts(498_000_000, 180199)
+			.saturating_add(RocksDbWeight::get().reads(67_u64))
+			.saturating_add(RocksDbWeight::get().writes(69_u64))
+	}
+	fn mint_nft() -> Weight {
+		Weight::from_parts(168_000_000, 11376)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	fn list_nft() -> Weight {
+		Weight::from_parts(21_000_000, 3850)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn update_price() -> Weight {
+		Weight::from_parts(19_000_000, 3850)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn delist_nft() -> Weight {
+		Weight::from_parts(17_000_000, 3850)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn update_nft_metadata(m: u32, ) -> Weight {
+		Weight::from_parts(47_000_000, 4859)
+			.saturating_add(Weight::from_parts(1_900, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn lock_nft_metadata() -> Weight {
+		Weight::from_parts(20_000_000, 4859)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn withdraw_fees() -> Weight {
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn create_auction() -> Weight {
+		Weight::from_parts(30_000_000, 5141)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn bid() -> Weight {
+		Weight::from_parts(135_000_000, 8762)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn cancel_auction() -> Weight {
+		Weight::from_parts(24_000_000, 5141)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn settle_auctions(n: u32, ) -> Weight {
 		Weight::from_parts(6_000_000, 1489)
+			.saturating_add(Weight::from_parts(98_000_000, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((10_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5141).saturating_mul(n.into()))
+	}
+	fn make_offer() -> Weight {
+		Weight::from_parts(116_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(44_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn accept_offer() -> Weight {
+		Weight::from_parts(132_000_000, 11376)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	fn clear_expired_offer() -> Weight {
+		Weight::from_parts(45_000_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn set_profile() -> Weight {
+		Weight::from_parts(34_000_000, 2928)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn clear_profile() -> Weight {
+		Weight::from_parts(24_000_000, 2928)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn link_fid() -> Weight {
+		Weight::from_parts(98_000_000, 5856)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	fn unlink_fid() -> Weight {
+		Weight::from_parts(30_000_000, 2928)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn request_engagement_check() -> Weight {
+		Weight::from_parts(21_000_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn submit_reaction() -> Weight {
+		Weight::from_parts(76_000_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn submit_engagement_verdict() -> Weight {
+		Weight::from_parts(78_000_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn clear_expired_engagement_check() -> Weight {
+		Weight::from_parts(20_000_000, 3545)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
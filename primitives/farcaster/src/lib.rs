@@ -0,0 +1,351 @@
+//! Decoding and verification of signed Farcaster protobuf messages.
+//!
+//! [`verify_message`] checks the BLAKE3 hash and ed25519 signature of an encoded `Message` and
+//! returns its data as typed values. The `FrameAction`, `ReactionAdd` and
+//! `VerificationAddEthAddress` message types are supported, which covers the `trustedData` of a
+//! Frame POST, on-chain engagement proofs and linking an Ethereum address to an FID.
+//!
+//! Only the fields read here are decoded; everything else is skipped, so newer message versions
+//! with extra fields still verify. See `message.proto` in the Farcaster protocol repository for
+//! the schema. The crate is `no_std` and verifies signatures through `sp_io`, so the same code
+//! runs in the runtime and natively in off-chain services.
+//!
+//! A valid signature only shows that the message was signed by [`Message::signer`]; callers must
+//! still check that the key is registered for [`Message::fid`] in the KeyRegistry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod protobuf;
+
+#[cfg(test)]
+mod tests;
+
+use alloc::{string::String, vec::Vec};
+use protobuf::{Fields, Value};
+use sp_core::ed25519;
+
+/// A Farcaster account id.
+pub type Fid = u64;
+/// Hash of a message: the first 20 bytes of the BLAKE3 digest of its data.
+pub type MessageHash = [u8; 20];
+
+/// Start of Farcaster time, 2021-01-01T00:00:00Z, in seconds since the Unix epoch.
+pub const FARCASTER_EPOCH: u64 = 1_609_459_200;
+
+/// `HashScheme::HASH_SCHEME_BLAKE3`
+const HASH_SCHEME_BLAKE3: u64 = 1;
+/// `SignatureScheme::SIGNATURE_SCHEME_ED25519`
+const SIGNATURE_SCHEME_ED25519: u64 = 1;
+/// `MessageType::MESSAGE_TYPE_REACTION_ADD`
+const MESSAGE_TYPE_REACTION_ADD: u64 = 3;
+/// `MessageType::MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS`
+const MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS: u64 = 7;
+/// `MessageType::MESSAGE_TYPE_FRAME_ACTION`
+const MESSAGE_TYPE_FRAME_ACTION: u64 = 13;
+/// `ReactionType::REACTION_TYPE_LIKE`
+const REACTION_TYPE_LIKE: u64 = 1;
+/// `ReactionType::REACTION_TYPE_RECAST`
+const REACTION_TYPE_RECAST: u64 = 2;
+/// `Protocol::PROTOCOL_ETHEREUM`
+const PROTOCOL_ETHEREUM: u64 = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageError {
+    /// The bytes are not a well-formed message.
+    Malformed,
+    /// The message uses a hash or signature scheme other than BLAKE3 and ed25519.
+    UnsupportedScheme,
+    /// The hash does not match the message data.
+    BadHash,
+    /// The signature does not match the hash and signer.
+    BadSignature,
+    /// The message type, reaction type or verification protocol is not one this crate decodes.
+    UnsupportedMessageType,
+}
+
+/// `FarcasterNetwork` the message was made for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FarcasterNetwork {
+    Mainnet,
+    Testnet,
+    Devnet,
+}
+
+/// A verified message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Message {
+    /// FID of the author.
+    pub fid: Fid,
+    /// Seconds since [`FARCASTER_EPOCH`].
+    pub timestamp: u32,
+    pub network: FarcasterNetwork,
+    pub body: MessageBody,
+    pub hash: MessageHash,
+    /// Key that signed the message.
+    pub signer: ed25519::Public,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessageBody {
+    FrameAction(FrameActionBody),
+    ReactionAdd(ReactionBody),
+    VerificationAddEthAddress(VerificationAddEthAddressBody),
+}
+
+/// Author and hash of a cast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CastId {
+    pub fid: Fid,
+    pub hash: MessageHash,
+}
+
+/// A button press on a Frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameActionBody {
+    /// URL of the Frame.
+    pub url: Vec<u8>,
+    /// 1-based index of the button pressed.
+    pub button_index: u32,
+    /// Cast the Frame was embedded in, if any.
+    pub cast_id: Option<CastId>,
+    pub input_text: Vec<u8>,
+    /// State the Frame server sent with the Frame.
+    pub state: Vec<u8>,
+    /// Hash of the transaction sent by a transaction button.
+    pub transaction_id: Vec<u8>,
+    /// Wallet address connected to the client.
+    pub address: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReactionType {
+    Like,
+    Recast,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReactionTarget {
+    Cast(CastId),
+    Url(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReactionBody {
+    pub kind: ReactionType,
+    pub target: ReactionTarget,
+}
+
+/// Claim that the author controls an Ethereum address.
+///
+/// Only the Farcaster signature is verified; `claim_signature` is the EIP-712 signature of the
+/// address over the claim and has to be checked separately.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationAddEthAddressBody {
+    pub address: [u8; 20],
+    pub claim_signature: Vec<u8>,
+    pub block_hash: [u8; 32],
+    /// 0 for an externally owned account, 1 for a contract wallet.
+    pub verification_type: u32,
+    /// Chain of a contract wallet, 0 for an externally owned account.
+    pub chain_id: u32,
+}
+
+/// Decode `message`, check its hash and signature, and return its data.
+pub fn verify_message(message: &[u8]) -> Result<Message, MessageError> {
+    let mut data = None;
+    let mut data_bytes = None;
+    let mut hash = None;
+    let mut hash_scheme = 0;
+    let mut signature = None;
+    let mut signature_scheme = 0;
+    let mut signer = None;
+    for field in Fields::new(message) {
+        match field? {
+            (1, Value::Bytes(bytes)) => data = Some(bytes),
+            (2, Value::Bytes(bytes)) => hash = Some(bytes),
+            (3, Value::Varint(scheme)) => hash_scheme = scheme,
+            (4, Value::Bytes(bytes)) => signature = Some(bytes),
+            (5, Value::Varint(scheme)) => signature_scheme = scheme,
+            (6, Value::Bytes(bytes)) => signer = Some(bytes),
+            (7, Value::Bytes(bytes)) => data_bytes = Some(bytes),
+            _ => {}
+        }
+    }
+    if hash_scheme != HASH_SCHEME_BLAKE3 || signature_scheme != SIGNATURE_SCHEME_ED25519 {
+        return Err(MessageError::UnsupportedScheme);
+    }
+
+    // Hubs set `data_bytes` when the data cannot be re-encoded byte for byte
+    let data = data_bytes.or(data).ok_or(MessageError::Malformed)?;
+    let hash: MessageHash = hash
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(MessageError::Malformed)?;
+    if blake3::hash(data).as_bytes()[..hash.len()] != hash {
+        return Err(MessageError::BadHash);
+    }
+    let signature = signature
+        .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+        .map(ed25519::Signature::from_raw)
+        .ok_or(MessageError::Malformed)?;
+    let signer = signer
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(ed25519::Public::from_raw)
+        .ok_or(MessageError::Malformed)?;
+    if !sp_io::crypto::ed25519_verify(&signature, &hash, &signer) {
+        return Err(MessageError::BadSignature);
+    }
+
+    let mut message_type = 0;
+    let mut fid = 0;
+    let mut timestamp = 0;
+    let mut network = 0;
+    let mut reaction_body = None;
+    let mut verification_body = None;
+    let mut frame_action_body = None;
+    for field in Fields::new(data) {
+        match field? {
+            (1, Value::Varint(value)) => message_type = value,
+            (2, Value::Varint(value)) => fid = value,
+            (3, Value::Varint(value)) => timestamp = value,
+            (4, Value::Varint(value)) => network = value,
+            (7, Value::Bytes(bytes)) => reaction_body = Some(bytes),
+            (9, Value::Bytes(bytes)) => verification_body = Some(bytes),
+            (16, Value::Bytes(bytes)) => frame_action_body = Some(bytes),
+            _ => {}
+        }
+    }
+    let network = match network {
+        1 => FarcasterNetwork::Mainnet,
+        2 => FarcasterNetwork::Testnet,
+        3 => FarcasterNetwork::Devnet,
+        _ => return Err(MessageError::Malformed),
+    };
+    let body = match message_type {
+        MESSAGE_TYPE_FRAME_ACTION => {
+            MessageBody::FrameAction(decode_frame_action(frame_action_body)?)
+        }
+        MESSAGE_TYPE_REACTION_ADD => MessageBody::ReactionAdd(decode_reaction(reaction_body)?),
+        MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS => {
+            MessageBody::VerificationAddEthAddress(decode_verification(verification_body)?)
+        }
+        _ => return Err(MessageError::UnsupportedMessageType),
+    };
+
+    Ok(Message {
+        fid,
+        timestamp: timestamp.try_into().map_err(|_| MessageError::Malformed)?,
+        network,
+        body,
+        hash,
+        signer,
+    })
+}
+
+fn decode_frame_action(body: Option<&[u8]>) -> Result<FrameActionBody, MessageError> {
+    let mut frame_action = FrameActionBody {
+        url: Vec::new(),
+        button_index: 0,
+        cast_id: None,
+        input_text: Vec::new(),
+        state: Vec::new(),
+        transaction_id: Vec::new(),
+        address: Vec::new(),
+    };
+    for field in Fields::new(body.ok_or(MessageError::Malformed)?) {
+        match field? {
+            (1, Value::Bytes(bytes)) => frame_action.url = bytes.into(),
+            (2, Value::Varint(value)) => {
+                frame_action.button_index = value.try_into().map_err(|_| MessageError::Malformed)?
+            }
+            (3, Value::Bytes(bytes)) => frame_action.cast_id = Some(decode_cast_id(bytes)?),
+            (4, Value::Bytes(bytes)) => frame_action.input_text = bytes.into(),
+            (5, Value::Bytes(bytes)) => frame_action.state = bytes.into(),
+            (6, Value::Bytes(bytes)) => frame_action.transaction_id = bytes.into(),
+            (7, Value::Bytes(bytes)) => frame_action.address = bytes.into(),
+            _ => {}
+        }
+    }
+    Ok(frame_action)
+}
+
+fn decode_reaction(body: Option<&[u8]>) -> Result<ReactionBody, MessageError> {
+    let mut reaction_type = 0;
+    let mut target = None;
+    for field in Fields::new(body.ok_or(MessageError::Malformed)?) {
+        match field? {
+            (1, Value::Varint(value)) => reaction_type = value,
+            (2, Value::Bytes(bytes)) => target = Some(ReactionTarget::Cast(decode_cast_id(bytes)?)),
+            (3, Value::Bytes(bytes)) => {
+                let url = String::from_utf8(bytes.into()).map_err(|_| MessageError::Malformed)?;
+                target = Some(ReactionTarget::Url(url));
+            }
+            _ => {}
+        }
+    }
+    let kind = match reaction_type {
+        REACTION_TYPE_LIKE => ReactionType::Like,
+        REACTION_TYPE_RECAST => ReactionType::Recast,
+        _ => return Err(MessageError::UnsupportedMessageType),
+    };
+    Ok(ReactionBody {
+        kind,
+        target: target.ok_or(MessageError::Malformed)?,
+    })
+}
+
+fn decode_verification(body: Option<&[u8]>) -> Result<VerificationAddEthAddressBody, MessageError> {
+    let mut address = None;
+    let mut claim_signature = Vec::new();
+    let mut block_hash = None;
+    let mut verification_type = 0;
+    let mut chain_id = 0;
+    let mut protocol = PROTOCOL_ETHEREUM;
+    for field in Fields::new(body.ok_or(MessageError::Malformed)?) {
+        match field? {
+            (1, Value::Bytes(bytes)) => address = Some(bytes),
+            (2, Value::Bytes(bytes)) => claim_signature = bytes.into(),
+            (3, Value::Bytes(bytes)) => block_hash = Some(bytes),
+            (4, Value::Varint(value)) => verification_type = value,
+            (5, Value::Varint(value)) => chain_id = value,
+            (7, Value::Varint(value)) => protocol = value,
+            _ => {}
+        }
+    }
+    if protocol != PROTOCOL_ETHEREUM {
+        return Err(MessageError::UnsupportedMessageType);
+    }
+
+    Ok(VerificationAddEthAddressBody {
+        address: address
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(MessageError::Malformed)?,
+        claim_signature,
+        block_hash: block_hash
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(MessageError::Malformed)?,
+        verification_type: verification_type
+            .try_into()
+            .map_err(|_| MessageError::Malformed)?,
+        chain_id: chain_id.try_into().map_err(|_| MessageError::Malformed)?,
+    })
+}
+
+fn decode_cast_id(bytes: &[u8]) -> Result<CastId, MessageError> {
+    let mut fid = 0;
+    let mut hash = None;
+    for field in Fields::new(bytes) {
+        match field? {
+            (1, Value::Varint(value)) => fid = value,
+            (2, Value::Bytes(bytes)) => hash = Some(bytes),
+            _ => {}
+        }
+    }
+    Ok(CastId {
+        fid,
+        hash: hash
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(MessageError::Malformed)?,
+    })
+}
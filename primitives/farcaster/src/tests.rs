@@ -0,0 +1,189 @@
+use crate::*;
+use core::ops::Range;
+use serde_json::Value as Json;
+use sp_core::bytes::from_hex;
+
+const VECTORS: &str = include_str!("../test-vectors/messages.json");
+
+fn bytes(value: &Json) -> Vec<u8> {
+    from_hex(value.as_str().unwrap()).unwrap()
+}
+
+fn array<const N: usize>(value: &Json) -> [u8; N] {
+    bytes(value).try_into().unwrap()
+}
+
+fn text(value: &Json) -> Vec<u8> {
+    value.as_str().unwrap().as_bytes().to_vec()
+}
+
+fn number<T: TryFrom<u64>>(value: &Json) -> T {
+    T::try_from(value.as_u64().unwrap()).ok().unwrap()
+}
+
+fn cast_id(value: &Json) -> CastId {
+    CastId {
+        fid: number(&value["fid"]),
+        hash: array(&value["hash"]),
+    }
+}
+
+fn expected_body(value: &Json) -> MessageBody {
+    if let Some(frame) = value.get("frameAction") {
+        return MessageBody::FrameAction(FrameActionBody {
+            url: text(&frame["url"]),
+            button_index: number(&frame["buttonIndex"]),
+            cast_id: (!frame["castId"].is_null()).then(|| cast_id(&frame["castId"])),
+            input_text: text(&frame["inputText"]),
+            state: text(&frame["state"]),
+            transaction_id: text(&frame["transactionId"]),
+            address: bytes(&frame["address"]),
+        });
+    }
+    if let Some(reaction) = value.get("reactionAdd") {
+        let kind = match reaction["type"].as_str().unwrap() {
+            "like" => ReactionType::Like,
+            "recast" => ReactionType::Recast,
+            other => panic!("unknown reaction type {other}"),
+        };
+        let target = match reaction.get("cast") {
+            Some(cast) => ReactionTarget::Cast(cast_id(cast)),
+            None => ReactionTarget::Url(reaction["url"].as_str().unwrap().into()),
+        };
+        return MessageBody::ReactionAdd(ReactionBody { kind, target });
+    }
+    let verification = &value["verificationAddEthAddress"];
+    MessageBody::VerificationAddEthAddress(VerificationAddEthAddressBody {
+        address: array(&verification["address"]),
+        claim_signature: bytes(&verification["claimSignature"]),
+        block_hash: array(&verification["blockHash"]),
+        verification_type: number(&verification["verificationType"]),
+        chain_id: number(&verification["chainId"]),
+    })
+}
+
+fn expected_message(value: &Json) -> Message {
+    Message {
+        fid: number(&value["fid"]),
+        timestamp: number(&value["timestamp"]),
+        network: match value["network"].as_str().unwrap() {
+            "mainnet" => FarcasterNetwork::Mainnet,
+            "testnet" => FarcasterNetwork::Testnet,
+            "devnet" => FarcasterNetwork::Devnet,
+            other => panic!("unknown network {other}"),
+        },
+        body: expected_body(&value["body"]),
+        hash: array(&value["hash"]),
+        signer: ed25519::Public::from_raw(array(&value["signer"])),
+    }
+}
+
+fn expected_error(value: &Json) -> MessageError {
+    match value.as_str().unwrap() {
+        "Malformed" => MessageError::Malformed,
+        "UnsupportedScheme" => MessageError::UnsupportedScheme,
+        "BadHash" => MessageError::BadHash,
+        "BadSignature" => MessageError::BadSignature,
+        "UnsupportedMessageType" => MessageError::UnsupportedMessageType,
+        other => panic!("unknown error {other}"),
+    }
+}
+
+/// Messages of the vectors that verify.
+fn valid_messages() -> Vec<Vec<u8>> {
+    let vectors: Json = serde_json::from_str(VECTORS).unwrap();
+    vectors["vectors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|vector| vector.get("result").is_some())
+        .map(|vector| bytes(&vector["message"]))
+        .collect()
+}
+
+/// Position of the value of the length-delimited field `number` of the encoded `Message`.
+fn field(message: &[u8], number: u64) -> Range<usize> {
+    for field in Fields::new(message) {
+        if let (n, Value::Bytes(bytes)) = field.unwrap() {
+            if n == number {
+                let start = bytes.as_ptr() as usize - message.as_ptr() as usize;
+                return start..start + bytes.len();
+            }
+        }
+    }
+    panic!("no field {number}");
+}
+
+#[test]
+fn test_vectors() {
+    let vectors: Json = serde_json::from_str(VECTORS).unwrap();
+    let vectors = vectors["vectors"].as_array().unwrap();
+    assert!(!vectors.is_empty());
+    for vector in vectors {
+        let name = vector["name"].as_str().unwrap();
+        let expected = match vector.get("result") {
+            Some(result) => Ok(expected_message(result)),
+            None => Err(expected_error(&vector["error"])),
+        };
+        assert_eq!(
+            verify_message(&bytes(&vector["message"])),
+            expected,
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn rejects_garbage() {
+    assert_eq!(verify_message(&[]), Err(MessageError::UnsupportedScheme));
+    assert_eq!(
+        verify_message(b"not a message"),
+        Err(MessageError::Malformed)
+    );
+    // A varint longer than ten bytes
+    assert_eq!(
+        verify_message(
+            &[0x08; 1]
+                .iter()
+                .chain(&[0xff; 11])
+                .copied()
+                .collect::<Vec<_>>()
+        ),
+        Err(MessageError::Malformed)
+    );
+}
+
+#[test]
+fn rejects_bad_signatures() {
+    let messages = valid_messages();
+    for (i, message) in messages.iter().enumerate() {
+        let signature = field(message, 4);
+        let mut tampered = message.clone();
+        tampered[signature.start] ^= 1;
+        assert_eq!(verify_message(&tampered), Err(MessageError::BadSignature));
+
+        // A signature by the same signer, but over the hash of another message
+        let other = &messages[(i + 1) % messages.len()];
+        let mut tampered = message.clone();
+        tampered[signature].copy_from_slice(&other[field(other, 4)]);
+        assert_eq!(verify_message(&tampered), Err(MessageError::BadSignature));
+    }
+}
+
+#[test]
+fn rejects_hash_mismatches() {
+    for message in valid_messages() {
+        let mut tampered = message.clone();
+        tampered[field(&message, 2).end - 1] ^= 1;
+        assert_eq!(verify_message(&tampered), Err(MessageError::BadHash));
+    }
+}
+
+#[test]
+fn rejects_truncated_messages() {
+    for message in valid_messages() {
+        for len in 0..message.len() {
+            assert!(verify_message(&message[..len]).is_err(), "{len} bytes");
+        }
+    }
+}
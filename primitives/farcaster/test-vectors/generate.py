@@ -0,0 +1,127 @@
+#!/usr/bin/env python3
+"""Generate messages.json: python3 generate.py > messages.json
+
+Independent of the Rust decoder: uses a reference BLAKE3 (single chunk) and the ed25519 of the
+`cryptography` package. Messages are signed by the key with seed 00 01 .. 1f."""
+import json, struct
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+from cryptography.hazmat.primitives import serialization
+
+# --- reference BLAKE3, single chunk (inputs up to 1024 bytes) ---
+IV = [0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19]
+PERM = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8]
+M32 = 0xffffffff
+def rotr(x, n): return ((x >> n) | (x << (32 - n))) & M32
+def g(s, a, b, c, d, x, y):
+    s[a] = (s[a] + s[b] + x) & M32; s[d] = rotr(s[d] ^ s[a], 16)
+    s[c] = (s[c] + s[d]) & M32; s[b] = rotr(s[b] ^ s[c], 12)
+    s[a] = (s[a] + s[b] + y) & M32; s[d] = rotr(s[d] ^ s[a], 8)
+    s[c] = (s[c] + s[d]) & M32; s[b] = rotr(s[b] ^ s[c], 7)
+def compress(cv, block, counter, block_len, flags):
+    m = list(struct.unpack('<16I', block))
+    s = cv[:] + IV[:4] + [counter & M32, counter >> 32, block_len, flags]
+    for r in range(7):
+        g(s, 0, 4, 8, 12, m[0], m[1]); g(s, 1, 5, 9, 13, m[2], m[3])
+        g(s, 2, 6, 10, 14, m[4], m[5]); g(s, 3, 7, 11, 15, m[6], m[7])
+        g(s, 0, 5, 10, 15, m[8], m[9]); g(s, 1, 6, 11, 12, m[10], m[11])
+        g(s, 2, 7, 8, 13, m[12], m[13]); g(s, 3, 4, 9, 14, m[14], m[15])
+        m = [m[i] for i in PERM]
+    return [s[i] ^ s[i + 8] for i in range(8)]
+def blake3(data):
+    assert len(data) <= 1024
+    blocks = [data[i:i + 64] for i in range(0, len(data), 64)] or [b'']
+    cv = IV[:]
+    for i, b in enumerate(blocks):
+        flags = (1 if i == 0 else 0) | ((2 | 8) if i == len(blocks) - 1 else 0)
+        cv = compress(cv, b.ljust(64, b'\0'), 0, len(b), flags)
+    return struct.pack('<8I', *cv)
+assert blake3(b'').hex() == 'af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262'
+
+# --- protobuf ---
+def varint(v):
+    out = b''
+    while v >= 0x80:
+        out += bytes([(v & 0x7f) | 0x80]); v >>= 7
+    return out + bytes([v])
+def uint(field, v): return varint(field << 3) + varint(v)
+def ln(field, b): return varint(field << 3 | 2) + varint(len(b)) + b
+
+key = Ed25519PrivateKey.from_private_bytes(bytes(range(32)))
+signer = key.public_key().public_bytes(serialization.Encoding.Raw, serialization.PublicFormat.Raw)
+other = Ed25519PrivateKey.from_private_bytes(bytes([0x42] * 32))
+
+def envelope(data, *, hash_scheme=1, sig_scheme=1, tamper=None, sign_with=key, data_bytes=False):
+    h = blake3(data)[:20]
+    sig = sign_with.sign(h)
+    body = data
+    if tamper == 'data':
+        body = data[:-1] + bytes([data[-1] ^ 1])
+    out = b''
+    if not data_bytes:
+        out += ln(1, body)
+    out += ln(2, h) + uint(3, hash_scheme) + ln(4, sig) + uint(5, sig_scheme) + ln(6, signer)
+    if data_bytes:
+        out += ln(7, body)
+    return out, h
+
+TS = 110_000_000
+CAST_HASH = bytes.fromhex('a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9')
+cast_id = uint(1, 3) + ln(2, CAST_HASH)
+def data(msg_type, fid, body_field, body, network=1):
+    return uint(1, msg_type) + uint(2, fid) + uint(3, TS) + uint(4, network) + ln(body_field, body)
+
+frame = (ln(1, b'https://example.com/frame') + uint(2, 2) + ln(3, cast_id) + ln(4, b'hello')
+         + ln(5, b'{"page":1}') + ln(6, b'0xabc') + ln(7, bytes.fromhex('11' * 20)))
+address = bytes.fromhex('8ba1f109551bd432803012645ac136ddd64dba72')
+claim = bytes(range(65))
+block_hash = bytes([0xbb] * 32)
+verification = ln(1, address) + ln(2, claim) + ln(3, block_hash) + uint(4, 0) + uint(5, 0) + uint(7, 0)
+
+vectors = []
+def ok(name, message, h, fid, network, body):
+    vectors.append({"name": name, "message": "0x" + message.hex(), "result": {
+        "fid": fid, "timestamp": TS, "network": network, "hash": "0x" + h.hex(),
+        "signer": "0x" + signer.hex(), "body": body}})
+def err(name, message, error):
+    vectors.append({"name": name, "message": "0x" + message.hex(), "error": error})
+cast = {"fid": 3, "hash": "0x" + CAST_HASH.hex()}
+
+m, h = envelope(data(13, 2, 16, frame))
+ok("frame action", m, h, 2, "mainnet", {"frameAction": {
+    "url": "https://example.com/frame", "buttonIndex": 2, "castId": cast, "inputText": "hello",
+    "state": '{"page":1}', "transactionId": "0xabc", "address": "0x" + "11" * 20}})
+m, h = envelope(data(13, 2, 16, ln(1, b'https://example.com/frame') + uint(2, 1)))
+ok("frame action without cast", m, h, 2, "mainnet", {"frameAction": {
+    "url": "https://example.com/frame", "buttonIndex": 1, "castId": None, "inputText": "",
+    "state": "", "transactionId": "", "address": "0x"}})
+m, h = envelope(data(3, 42, 7, uint(1, 1) + ln(2, cast_id)))
+ok("like", m, h, 42, "mainnet", {"reactionAdd": {"type": "like", "cast": cast}})
+m, h = envelope(data(3, 42, 7, uint(1, 2) + ln(3, b'https://example.com')), data_bytes=True)
+ok("recast of a url in data_bytes", m, h, 42, "mainnet", {"reactionAdd": {"type": "recast", "url": "https://example.com"}})
+m, h = envelope(data(7, 42, 9, verification, network=2))
+ok("eth address verification on testnet", m, h, 42, "testnet", {"verificationAddEthAddress": {
+    "address": "0x" + address.hex(), "claimSignature": "0x" + claim.hex(),
+    "blockHash": "0x" + block_hash.hex(), "verificationType": 0, "chainId": 0}})
+
+m, _ = envelope(data(3, 42, 7, uint(1, 1) + ln(2, cast_id)), tamper='data')
+err("tampered data", m, "BadHash")
+m, _ = envelope(data(3, 42, 7, uint(1, 1) + ln(2, cast_id)), sign_with=other)
+err("signed by another key", m, "BadSignature")
+m, _ = envelope(data(3, 42, 7, uint(1, 1) + ln(2, cast_id)), hash_scheme=2)
+err("unknown hash scheme", m, "UnsupportedScheme")
+m, _ = envelope(data(1, 42, 5, ln(4, b'gm')))
+err("cast add", m, "UnsupportedMessageType")
+m, _ = envelope(data(4, 42, 7, uint(1, 1) + ln(2, cast_id)))
+err("reaction remove", m, "UnsupportedMessageType")
+m, _ = envelope(data(3, 42, 16, frame))
+err("reaction add with a frame action body", m, "Malformed")
+m, _ = envelope(data(7, 42, 9, ln(1, bytes(32)) + ln(2, claim) + ln(3, block_hash) + uint(7, 1)))
+err("solana address verification", m, "UnsupportedMessageType")
+m, _ = envelope(data(3, 42, 7, uint(1, 9) + ln(2, cast_id)))
+err("unknown reaction type", m, "UnsupportedMessageType")
+m, _ = envelope(data(3, 42, 7, uint(1, 1) + ln(2, cast_id), network=9))
+err("unknown network", m, "Malformed")
+m, _ = envelope(data(3, 42, 7, uint(1, 1) + ln(2, cast_id)))
+err("truncated", m[:-5], "Malformed")
+
+print(json.dumps({"signer": "0x" + signer.hex(), "vectors": vectors}, indent=2))
@@ -25,14 +25,16 @@
 
 // External crates imports
 use alloc::vec::Vec;
+use codec::Codec;
 use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
 	weights::Weight,
 };
 use pallet_aura::Authorities;
+use pallet_parachain_template::{ArtistProfile, Collection, CollectionId, Fid, ItemId, NFT};
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_core::{crypto::KeyTypeId, ed25519, OpaqueMetadata, H160};
 use sp_runtime::{
 	traits::Block as BlockT,
 	transaction_validity::{TransactionSource, TransactionValidity},
@@ -42,11 +44,74 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-	SLOT_DURATION, VERSION,
+	AccountId, Balance, Block, ConsensusHook, Executive, FarcasterRegistry, InherentDataExt, Nonce,
+	ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TemplatePallet,
+	TransactionPayment, SLOT_DURATION, VERSION,
 };
 
+/// An NFT with its asking price, `None` when it is not for sale.
+pub type ListedNft<AccountId, Balance> = (NFT<AccountId, Balance>, Option<Balance>);
+
+sp_api::decl_runtime_apis! {
+	/// Read access to the NFT marketplace pallet for RPCs and off-chain clients.
+	///
+	/// Paginated calls return at most `limit` entries (capped by
+	/// `pallet_parachain_template::MAX_PAGE_SIZE`) starting after `cursor`. NFTs come with
+	/// their asking price as a [`ListedNft`].
+	pub trait NftMarketplaceApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Existing collections.
+		fn collections(
+			cursor: Option<CollectionId>,
+			limit: u32,
+		) -> Vec<(CollectionId, Collection<AccountId>)>;
+
+		/// NFTs owned by `account`, resolved through the pallet's owner index.
+		fn nfts_of_owner(
+			account: AccountId,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Vec<(CollectionId, ItemId, ListedNft<AccountId, Balance>)>;
+
+		/// Items minted in `collection_id`.
+		fn collection_items(
+			collection_id: CollectionId,
+			cursor: Option<ItemId>,
+			limit: u32,
+		) -> Vec<(ItemId, ListedNft<AccountId, Balance>)>;
+
+		/// A single NFT, if it exists.
+		fn nft_details(collection_id: CollectionId, item_id: ItemId) -> Option<ListedNft<AccountId, Balance>>;
+
+		/// The asking price of an item, if it is currently listed for sale.
+		fn listing_price(collection_id: CollectionId, item_id: ItemId) -> Option<Balance>;
+
+		/// The profile of an artist, if they have set one.
+		fn artist_profile(artist: AccountId) -> Option<ArtistProfile>;
+
+		/// The artist whose profile claims a Farcaster FID, with that profile.
+		fn artist_by_fid(fid: Fid) -> Option<(AccountId, ArtistProfile)>;
+	}
+
+	/// Read access to the on-chain mirror of the Farcaster IdRegistry and KeyRegistry.
+	pub trait FarcasterRegistryApi {
+		/// Signer keys registered for `fid`.
+		fn signers(fid: Fid) -> Vec<ed25519::Public>;
+
+		/// Custody address owning `fid`, if it is registered.
+		fn custody_address(fid: Fid) -> Option<H160>;
+
+		/// FID owned by the custody address `address`, if any.
+		fn fid_of_custody(address: H160) -> Option<Fid>;
+
+		/// Sequence number of the next registry batch the oracle committee must submit.
+		fn next_sequence() -> u64;
+	}
+}
+
 // we move some impls outside so we can easily use them with `docify`.
 impl Runtime {
 	#[docify::export]
@@ -210,6 +275,74 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl self::NftMarketplaceApi<Block, AccountId, Balance> for Runtime {
+		fn collections(
+			cursor: Option<CollectionId>,
+			limit: u32,
+		) -> Vec<(CollectionId, Collection<AccountId>)> {
+			TemplatePallet::list_collections(cursor, limit)
+		}
+
+		fn nfts_of_owner(
+			account: AccountId,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Vec<(CollectionId, ItemId, ListedNft<AccountId, Balance>)> {
+			TemplatePallet::nfts_of_owner(account, cursor, limit)
+				.into_iter()
+				.map(|(collection_id, item_id, nft)| {
+					(collection_id, item_id, (nft, TemplatePallet::listing_price(collection_id, item_id)))
+				})
+				.collect()
+		}
+
+		fn collection_items(
+			collection_id: CollectionId,
+			cursor: Option<ItemId>,
+			limit: u32,
+		) -> Vec<(ItemId, ListedNft<AccountId, Balance>)> {
+			TemplatePallet::collection_items(collection_id, cursor, limit)
+				.into_iter()
+				.map(|(item_id, nft)| (item_id, (nft, TemplatePallet::listing_price(collection_id, item_id))))
+				.collect()
+		}
+
+		fn nft_details(collection_id: CollectionId, item_id: ItemId) -> Option<ListedNft<AccountId, Balance>> {
+			TemplatePallet::nft_details(collection_id, item_id)
+				.map(|nft| (nft, TemplatePallet::listing_price(collection_id, item_id)))
+		}
+
+		fn listing_price(collection_id: CollectionId, item_id: ItemId) -> Option<Balance> {
+			TemplatePallet::listing_price(collection_id, item_id)
+		}
+
+		fn artist_profile(artist: AccountId) -> Option<ArtistProfile> {
+			TemplatePallet::artist_profile(artist)
+		}
+
+		fn artist_by_fid(fid: Fid) -> Option<(AccountId, ArtistProfile)> {
+			TemplatePallet::artist_by_fid(fid)
+		}
+	}
+
+	impl self::FarcasterRegistryApi<Block> for Runtime {
+		fn signers(fid: Fid) -> Vec<ed25519::Public> {
+			FarcasterRegistry::signers_of(fid)
+		}
+
+		fn custody_address(fid: Fid) -> Option<H160> {
+			FarcasterRegistry::fid_custody(fid)
+		}
+
+		fn fid_of_custody(address: H160) -> Option<Fid> {
+			FarcasterRegistry::custody_fid(address)
+		}
+
+		fn next_sequence() -> u64 {
+			FarcasterRegistry::next_sequence()
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
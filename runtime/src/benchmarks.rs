@@ -33,4 +33,7 @@ frame_benchmarking::define_benchmarks!(
 	[pallet_collator_selection, CollatorSelection]
 	[cumulus_pallet_parachain_system, ParachainSystem]
 	[cumulus_pallet_xcmp_queue, XcmpQueue]
+	[pallet_parachain_template, TemplatePallet]
+	[pallet_farcaster_registry, FarcasterRegistry]
+	[pallet_social_oracle, SocialOracle]
 );
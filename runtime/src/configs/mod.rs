@@ -33,7 +33,8 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     traits::{
-        ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, TransformOrigin, VariantCountOf,
+        ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, IsInVec, TransformOrigin,
+        VariantCountOf,
     },
     weights::{ConstantMultiplier, Weight},
     PalletId,
@@ -48,18 +49,19 @@ use polkadot_runtime_common::{
     xcm_sender::NoPriceForMessageDelivery, BlockHashCount, SlowAdjustingFeeUpdate,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::Perbill;
+use sp_runtime::{transaction_validity::TransactionPriority, Perbill};
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::BodyId;
 
 // Local module imports
 use super::{
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
-    AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook, Hash,
-    MessageQueue, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall, RuntimeEvent,
-    RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys,
-    System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, HOURS,
-    MAXIMUM_BLOCK_WEIGHT, MICRO_UNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook,
+    FarcasterRegistry, Hash, MessageQueue, Nonce, OracleCommittee, PalletInfo, ParachainSystem,
+    Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin,
+    RuntimeTask, Session, SessionKeys, SocialOracle, System, UncheckedExtrinsic, WeightToFee,
+    XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT,
+    MICRO_UNIT, MILLI_UNIT, MINUTES, NORMAL_DISPATCH_RATIO, SLOT_DURATION, UNIT, VERSION,
 };
 use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
 
@@ -92,6 +94,25 @@ parameter_types! {
         .build_or_panic();
     pub const SS58Prefix: u16 = 42;
     pub const TemplatePalletId: frame_support::PalletId = frame_support::PalletId(*b"template");
+    pub const MaxCollectionRoyalty: Perbill = Perbill::from_percent(25);
+    /// 2.5% of every NFT sale.
+    pub const NftMarketplaceFee: Perbill = Perbill::from_parts(25_000_000);
+    pub const MinAuctionDuration: BlockNumber = 10 * MINUTES;
+    pub const MaxAuctionDuration: BlockNumber = 30 * DAYS;
+    /// Bids in the last five minutes push the auction end back to five minutes out.
+    pub const AuctionAntiSnipingPeriod: BlockNumber = 5 * MINUTES;
+    pub const ArtistRegistrationFee: Balance = 100 * EXISTENTIAL_DEPOSIT;
+    pub const ArtistBond: Balance = 10 * UNIT;
+    pub const NftCollectionDeposit: Balance = 100 * MILLI_UNIT;
+    pub const NftItemDeposit: Balance = 10 * MILLI_UNIT;
+    pub const NftDepositPerByte: Balance = 100 * MICRO_UNIT;
+    pub const NftMinOfferAmount: Balance = 10 * MILLI_UNIT;
+    pub const NftMaxOfferDuration: BlockNumber = 30 * DAYS;
+    pub const NftUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    pub const NftEngagementCheckTimeout: BlockNumber = DAYS;
+    /// Keys of the engagement attestation service. Purchases from gated collections fail until
+    /// one is added. Kept in storage so keys can be added without a runtime upgrade.
+    pub storage NftTrustedAttesters: alloc::vec::Vec<sp_core::ed25519::Public> = alloc::vec::Vec::new();
 }
 
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
@@ -310,4 +331,148 @@ impl pallet_parachain_template::Config for Runtime {
     type WeightInfo = pallet_parachain_template::weights::SubstrateWeight<Runtime>;
     type Currency = Balances;
     type PalletId = TemplatePalletId;
+    type MaxRoyalty = MaxCollectionRoyalty;
+    type MarketplaceFee = NftMarketplaceFee;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type MinAuctionDuration = MinAuctionDuration;
+    type MaxAuctionDuration = MaxAuctionDuration;
+    type AntiSnipingPeriod = AuctionAntiSnipingPeriod;
+    type MaxAuctionsPerBlock = ConstU32<50>;
+    type MaxOffersPerItem = ConstU32<20>;
+    type MinOfferAmount = NftMinOfferAmount;
+    type MaxOfferDuration = NftMaxOfferDuration;
+    type MaxBatchSize = ConstU32<50>;
+    // Each item can carry `MaxOffersPerItem` offers to cancel, so a full step stays within a
+    // block's proof size.
+    type RemoveItemsLimit = ConstU32<20>;
+    type ArtistRegistrationFee = ArtistRegistrationFee;
+    type ArtistBond = ArtistBond;
+    type ArtistAdminOrigin = EnsureRoot<AccountId>;
+    type FidSigners = pallet_farcaster_registry::RegisteredSigners<Runtime>;
+    type CollectionDeposit = NftCollectionDeposit;
+    type ItemDeposit = NftItemDeposit;
+    type DepositPerByte = NftDepositPerByte;
+    type TrustedAttesters = IsInVec<NftTrustedAttesters>;
+    type MaxEngagementChecksPerBlock = ConstU32<10>;
+    type MaxPendingEngagementChecks = ConstU32<10>;
+    type EngagementCheckTimeout = NftEngagementCheckTimeout;
+    type UnsignedPriority = NftUnsignedPriority;
+    type SocialAttestations = OracleAttestations;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = NftBenchmarkHelper;
+}
+
+/// Lets the NFT pallet benchmarks sign attestations and Farcaster messages with keys of their
+/// own.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_parachain_template::BenchmarkHelper for NftBenchmarkHelper {
+    fn trust_attester(attester: sp_core::ed25519::Public) {
+        NftTrustedAttesters::set(&alloc::vec![attester]);
+    }
+
+    fn add_fid_signer(fid: pallet_parachain_template::Fid, signer: sp_core::ed25519::Public) {
+        pallet_farcaster_registry::FidSigners::<Runtime>::insert(fid, signer, ());
+    }
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+parameter_types! {
+    pub const MaxOracleMembers: u32 = 16;
+    /// Two thirds of the oracle committee must agree on a registry batch.
+    pub const RegistryBatchThreshold: Perbill = Perbill::from_percent(67);
+}
+
+/// Oracle committee allowed to feed Farcaster registry events to `FarcasterRegistry` and to bond
+/// into `SocialOracle`.
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = EnsureRoot<AccountId>;
+    type RemoveOrigin = EnsureRoot<AccountId>;
+    type SwapOrigin = EnsureRoot<AccountId>;
+    type ResetOrigin = EnsureRoot<AccountId>;
+    type PrimeOrigin = EnsureRoot<AccountId>;
+    type MembershipInitialized = ();
+    type MembershipChanged = FarcasterRegistry;
+    type MaxMembers = MaxOracleMembers;
+    type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_farcaster_registry::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_farcaster_registry::weights::SubstrateWeight<Runtime>;
+    type Committee = OracleCommittee;
+    type Threshold = RegistryBatchThreshold;
+    type MaxMembers = MaxOracleMembers;
+    type MaxEventsPerBatch = ConstU32<100>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+    pub const OracleMemberBond: Balance = 100 * UNIT;
+    pub const OracleDisputeDeposit: Balance = 10 * UNIT;
+    pub const OracleSlashFraction: Perbill = Perbill::from_percent(50);
+    /// Two thirds of the bonded oracle members must agree on an attestation.
+    pub const OracleAttestationThreshold: Perbill = Perbill::from_percent(67);
+}
+
+impl pallet_social_oracle::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_social_oracle::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type MemberBond = OracleMemberBond;
+    type Candidates = OracleCommittee;
+    type Threshold = OracleAttestationThreshold;
+    type MaxMembers = MaxOracleMembers;
+    type UnbondingPeriod = ConstU32<{ 7 * DAYS }>;
+    type SubmissionLifetime = ConstU32<DAYS>;
+    type DisputeDeposit = OracleDisputeDeposit;
+    type SlashFraction = OracleSlashFraction;
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+}
+
+/// Finalised, undisputed attestations of [`pallet_social_oracle`], as read by the NFT pallet.
+pub struct OracleAttestations;
+
+impl pallet_parachain_template::SocialAttestations for OracleAttestations {
+    fn engagement(
+        fid: pallet_parachain_template::Fid,
+        cast_hash: &pallet_parachain_template::CastHash,
+    ) -> pallet_parachain_template::EngagementActions {
+        use pallet_social_oracle::Subject;
+        let attested = |subject| SocialOracle::attested(&subject).is_some_and(|value| value > 0);
+        pallet_parachain_template::EngagementActions {
+            like: attested(Subject::Liked {
+                fid,
+                cast_hash: *cast_hash,
+            }),
+            recast: attested(Subject::Recasted {
+                fid,
+                cast_hash: *cast_hash,
+            }),
+        }
+    }
+
+    fn follows(
+        fid: pallet_parachain_template::Fid,
+        target: pallet_parachain_template::Fid,
+    ) -> bool {
+        SocialOracle::attested(&pallet_social_oracle::Subject::Follows { fid, target })
+            .is_some_and(|value| value > 0)
+    }
+
+    fn follower_count(fid: pallet_parachain_template::Fid) -> Option<u64> {
+        SocialOracle::attested(&pallet_social_oracle::Subject::FollowerCount { fid })
+    }
 }
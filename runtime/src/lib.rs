@@ -95,7 +95,10 @@ pub type UncheckedExtrinsic =
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_parachain_template::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_parachain_template::migrations::v2::MigrateV1ToV2<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -168,10 +171,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("parachain-template-runtime"),
 	impl_name: create_runtime_str!("parachain-template-runtime"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -308,6 +311,12 @@ mod runtime {
 	// Template
 	#[runtime::pallet_index(50)]
 	pub type TemplatePallet = pallet_parachain_template;
+	#[runtime::pallet_index(51)]
+	pub type OracleCommittee = pallet_membership<Instance1>;
+	#[runtime::pallet_index(52)]
+	pub type FarcasterRegistry = pallet_farcaster_registry;
+	#[runtime::pallet_index(53)]
+	pub type SocialOracle = pallet_social_oracle;
 }
 
 #[docify::export(register_validate_block)]